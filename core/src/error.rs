@@ -4,7 +4,9 @@ use std::fmt::{self, Display, Formatter};
 mod key_error;
 mod type_error;
 mod value_error;
+mod location;
 
+pub use location::Location;
 pub use type_error::TypeError;
 pub use key_error::KeyError;
 pub use value_error::ValueError;
@@ -34,7 +36,57 @@ pub enum Error {
 	Boxed(Box<dyn std::error::Error + 'static>),
 
 	/// Returning a value
-	Return { to: Binding, obj: Object }
+	Return { to: Binding, obj: Object },
+
+	/// An error that occurred at a known location; see [`Error::at`](#method.at).
+	Located { location: Location, error: Box<Error> }
+}
+
+impl Error {
+	/// Attach `location` to this error.
+	///
+	/// Errors that already have a location keep it, as it's going to be the more precise one.
+	/// [`Return`](#variant.Return)s are left alone, as they're used for control flow.
+	pub fn at(self, location: Location) -> Self {
+		match self {
+			err @ Error::Located { .. } | err @ Error::Return { .. } => err,
+			error => Error::Located { location, error: Box::new(error) }
+		}
+	}
+
+	/// Get the location this error occurred at, if it's known.
+	pub fn location(&self) -> Option<&Location> {
+		match self {
+			Error::Located { location, .. } => Some(location),
+			_ => None
+		}
+	}
+}
+
+impl PartialEq for Error {
+	/// Compare two errors.
+	///
+	/// [`Internal`](#variant.Internal) errors compare by their static string, and
+	/// [`Boxed`](#variant.Boxed) errors by their `Display`ed messages. The binding and object of a
+	/// [`Return`](#variant.Return) are compared by identity.
+	fn eq(&self, rhs: &Error) -> bool {
+		use Error::*;
+
+		match (self, rhs) {
+			(Internal(l), Internal(r)) => l == r,
+			(Messaged(l), Messaged(r)) => l == r,
+			(KeyError(l), KeyError(r)) => l == r,
+			(TypeError(l), TypeError(r)) => l == r,
+			(ValueError(l), ValueError(r)) => l == r,
+			(AssertionFailed(l), AssertionFailed(r)) => l == r,
+			(Boxed(l), Boxed(r)) => l.to_string() == r.to_string(),
+			(Return { to: lt, obj: lo }, Return { to: rt, obj: ro }) =>
+				lt.as_ref().is_identical(rt.as_ref()) && lo.is_identical(ro),
+			(Located { location: ll, error: le }, Located { location: rl, error: re }) =>
+				ll == rl && le == re,
+			_ => false
+		}
+	}
 }
 
 impl From<String> for Error {
@@ -68,7 +120,8 @@ impl Display for Error {
 			Error::AssertionFailed(Some(err)) => write!(f, "assertion failed: {}", err),
 			Error::AssertionFailed(None) => write!(f, "assertion failed"),
 			Error::Boxed(err) => Display::fmt(&err, f),
-			Error::Return { to, obj } => write!(f, "uncaught return to {:?}: {:?}", to, obj)
+			Error::Return { to, obj } => write!(f, "uncaught return to {:?}: {:?}", to, obj),
+			Error::Located { location, error } => write!(f, "{}: {}", location, error),
 		}
 	}
 }
@@ -77,6 +130,7 @@ impl std::error::Error for Error {
 	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
 		match self {
 			Error::Boxed(err) => Some(err.as_ref()),
+			Error::Located { error, .. } => error.source(),
 			_ => None
 		}
 	}
@@ -84,3 +138,88 @@ impl std::error::Error for Error {
 
 #[must_use]
 pub type Result<T> = ::std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn without_location() {
+		let err = Error::from(ValueError::Messaged("oops".into()));
+
+		assert!(err.location().is_none());
+		assert_eq!(err.to_string(), "type error: oops");
+	}
+
+	#[test]
+	fn with_location() {
+		let location = Location::new(Some("meno.qs".into()), 3, 12);
+		let err = Error::from(ValueError::Messaged("oops".into())).at(location.clone());
+
+		assert_eq!(err.location(), Some(&location));
+		assert_eq!(err.to_string(), "meno.qs:3:12: type error: oops");
+
+		let err = Error::Internal("bad").at(Location::new(None, 1, 0));
+		assert_eq!(err.to_string(), "<eval>:1:0: internal error: bad");
+	}
+
+	#[test]
+	fn eq() {
+		assert_eq!(Error::Internal("bad"), Error::Internal("bad"));
+		assert_ne!(Error::Internal("bad"), Error::Internal("worse"));
+		assert_eq!(Error::Messaged("bad".into()), Error::Messaged("bad".into()));
+		assert_ne!(Error::Messaged("bad".into()), Error::Internal("bad"));
+
+		assert_eq!(
+			Error::from(KeyError::NoThisSupplied),
+			Error::KeyError(KeyError::NoThisSupplied)
+		);
+		assert_eq!(
+			Error::from(TypeError::WrongType { expected: "Text", got: "Null" }),
+			Error::TypeError(TypeError::WrongType { expected: "Text", got: "Null" })
+		);
+		assert_eq!(
+			Error::from(ValueError::Messaged("bad".into())),
+			Error::ValueError(ValueError::Messaged("bad".into()))
+		);
+
+		assert_eq!(Error::AssertionFailed(None), Error::AssertionFailed(None));
+		assert_ne!(Error::AssertionFailed(None), Error::AssertionFailed(Some("x".into())));
+
+		let boxed = || Error::Boxed(Box::new(std::fmt::Error));
+		assert_eq!(boxed(), boxed());
+
+		let location = Location::new(None, 1, 2);
+		assert_eq!(
+			Error::Internal("bad").at(location.clone()),
+			Error::Internal("bad").at(location)
+		);
+		assert_ne!(Error::Internal("bad").at(Location::default()), Error::Internal("bad"));
+	}
+
+	#[test]
+	fn eq_return() {
+		let to = Binding::from(Object::from(1));
+		let obj = Object::from(2);
+
+		assert_eq!(
+			Error::Return { to: to.clone(), obj: obj.clone() },
+			Error::Return { to: to.clone(), obj: obj.clone() }
+		);
+		assert_ne!(
+			Error::Return { to: to.clone(), obj },
+			Error::Return { to, obj: Object::from(2) }
+		);
+	}
+
+	#[test]
+	fn innermost_location_is_kept() {
+		let inner = Location::new(Some("inner.qs".into()), 1, 1);
+		let err = Error::Internal("bad")
+			.at(inner.clone())
+			.at(Location::new(Some("outer.qs".into()), 2, 2));
+
+		assert_eq!(err.location(), Some(&inner));
+	}
+}
+
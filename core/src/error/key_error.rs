@@ -3,12 +3,39 @@ use crate::Object;
 
 #[derive(Debug, Clone)]
 pub enum KeyError {
+	/// Index `idx` was out of range for a collection of length `len`.
 	OutOfBounds { idx: isize, len: usize },
+
+	/// The range `slice` was out of range for a collection of length `len`.
 	BadSlice { slice: String, len: usize },
+
+	/// The attribute `attr` wasn't found on `obj` (nor any of its parents).
 	DoesntExist { attr: Object, obj: Object },
+
 	NoThisSupplied
 }
 
+impl PartialEq for KeyError {
+	/// Compare two key errors.
+	///
+	/// The `attr` and `obj` of [`DoesntExist`](#variant.DoesntExist) are compared by identity, as
+	/// calling a Quest `==` method could itself fail.
+	fn eq(&self, rhs: &KeyError) -> bool {
+		use KeyError::*;
+
+		match (self, rhs) {
+			(OutOfBounds { idx: li, len: ll }, OutOfBounds { idx: ri, len: rl }) =>
+				li == ri && ll == rl,
+			(BadSlice { slice: ls, len: ll }, BadSlice { slice: rs, len: rl }) =>
+				ls == rs && ll == rl,
+			(DoesntExist { attr: la, obj: lo }, DoesntExist { attr: ra, obj: ro }) =>
+				la.is_identical(ra) && lo.is_identical(ro),
+			(NoThisSupplied, NoThisSupplied) => true,
+			_ => false
+		}
+	}
+}
+
 impl From<KeyError> for super::Error {
 	fn from(key_error: KeyError) -> Self {
 		Self::KeyError(key_error)
@@ -30,3 +57,64 @@ impl Display for KeyError {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn eq() {
+		let out_of_bounds = KeyError::OutOfBounds { idx: -3, len: 2 };
+		assert_eq!(out_of_bounds, KeyError::OutOfBounds { idx: -3, len: 2 });
+		assert_ne!(out_of_bounds, KeyError::OutOfBounds { idx: 3, len: 2 });
+
+		assert_eq!(
+			KeyError::BadSlice { slice: "1..4".into(), len: 2 },
+			KeyError::BadSlice { slice: "1..4".into(), len: 2 }
+		);
+		assert_ne!(
+			KeyError::BadSlice { slice: "1..4".into(), len: 2 },
+			KeyError::BadSlice { slice: "1..4".into(), len: 3 }
+		);
+
+		assert_eq!(KeyError::NoThisSupplied, KeyError::NoThisSupplied);
+		assert_ne!(KeyError::NoThisSupplied, KeyError::OutOfBounds { idx: 0, len: 0 });
+	}
+
+	#[test]
+	fn missing_attr_and_out_of_bounds_are_distinct() {
+		use crate::Error;
+
+		let obj = Object::from(1);
+		match obj.get_attr_lit("not_an_attr").unwrap_err() {
+			Error::KeyError(KeyError::DoesntExist { attr, obj: on }) => {
+				assert_eq!(attr.downcast_ref::<crate::types::Text>().unwrap().as_ref(), "not_an_attr");
+				assert!(on.is_identical(&obj));
+			},
+			err => panic!("expected DoesntExist, got {:?}", err)
+		}
+
+		let list = Object::from(crate::types::List::from(vec![Object::from(1), Object::from(2)]));
+		assert_eq!(
+			list.call_attr_lit("[]=", &[&(-5).into(), &3.into()]).unwrap_err(),
+			KeyError::OutOfBounds { idx: -5, len: 2 }.into()
+		);
+	}
+
+	#[test]
+	fn eq_doesnt_exist() {
+		let attr = Object::from("foo");
+		let obj = Object::from(1);
+
+		assert_eq!(
+			KeyError::DoesntExist { attr: attr.clone(), obj: obj.clone() },
+			KeyError::DoesntExist { attr: attr.clone(), obj: obj.clone() }
+		);
+
+		// objects are compared by identity, not by value.
+		assert_ne!(
+			KeyError::DoesntExist { attr: attr.clone(), obj: obj.clone() },
+			KeyError::DoesntExist { attr: Object::from("foo"), obj }
+		);
+	}
+}
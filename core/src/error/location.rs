@@ -0,0 +1,34 @@
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+
+/// Where in a Quest program something happened.
+///
+/// This is a lightweight equivalent of the parser's context, as `quest_core` doesn't know about the
+/// parser.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Location {
+	/// The file, if one exists, that the location is in.
+	pub file: Option<PathBuf>,
+	/// The line number, starting at `1`.
+	pub lineno: usize,
+	/// The column within the line.
+	pub column: usize
+}
+
+impl Location {
+	/// Create a new location.
+	pub fn new(file: Option<PathBuf>, lineno: usize, column: usize) -> Self {
+		Location { file, lineno, column }
+	}
+}
+
+impl Display for Location {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self.file {
+			Some(ref file) => write!(f, "{}", file.display())?,
+			None => write!(f, "<eval>")?
+		}
+
+		write!(f, ":{}:{}", self.lineno, self.column)
+	}
+}
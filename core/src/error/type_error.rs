@@ -1,8 +1,18 @@
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TypeError {
 	WrongType { expected: &'static str, got: &'static str },
+
+	/// Argument number `index` to `method` couldn't be converted to `expected`.
+	BadArgument { method: &'static str, index: usize, expected: &'static str, got: &'static str },
+
+	/// `method` (if it's known) needed argument number `missing`, but was only given `got`
+	/// arguments.
+	///
+	/// This doesn't say how many arguments `method` takes in total, as it's raised by the first
+	/// argument that's missing.
+	ArgumentCount { method: Option<&'static str>, missing: usize, got: usize },
 }
 
 impl From<TypeError> for super::Error {
@@ -17,6 +27,45 @@ impl Display for TypeError {
 		match self {
 			TypeError::WrongType { expected, got } => 
 				write!(f, "expected type '{}' but got type '{}'", expected, got),
+			TypeError::BadArgument { method, index, expected, got } =>
+				write!(f, "argument {} to '{}' should be convertible to '{}' but got type '{}'",
+					index, method, expected, got),
+			TypeError::ArgumentCount { method: Some(method), missing, got } =>
+				write!(f, "argument {} to '{}' is missing (only got {})", missing, method, got),
+			TypeError::ArgumentCount { method: None, missing, got } =>
+				write!(f, "argument {} is missing (only got {})", missing, got),
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn eq() {
+		let wrong_type = TypeError::WrongType { expected: "Number", got: "Text" };
+
+		assert_eq!(wrong_type, TypeError::WrongType { expected: "Number", got: "Text" });
+		assert_ne!(wrong_type, TypeError::WrongType { expected: "Number", got: "List" });
+	}
+
+	#[test]
+	fn bad_argument_display() {
+		let err = TypeError::BadArgument { method: "+", index: 0, expected: "Number", got: "Null" };
+
+		assert_eq!(
+			err.to_string(),
+			"type error: argument 0 to '+' should be convertible to 'Number' but got type 'Null'"
+		);
+	}
+
+	#[test]
+	fn argument_count_display() {
+		let err = TypeError::ArgumentCount { method: Some("between"), missing: 1, got: 1 };
+		assert_eq!(err.to_string(), "type error: argument 1 to 'between' is missing (only got 1)");
+
+		let err = TypeError::ArgumentCount { method: None, missing: 0, got: 0 };
+		assert_eq!(err.to_string(), "type error: argument 0 is missing (only got 0)");
+	}
+}
@@ -1,8 +1,16 @@
 use std::fmt::{self, Display, Formatter};
+use crate::types::Number;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ValueError {
 	BadValue { expected: String, got: String },
+	/// A division (or modulo) by zero was attempted on the contained dividend.
+	DivisionByZero(Number),
+	/// The maximum stack depth was exceeded, usually by unbounded recursion.
+	StackOverflow { max_depth: usize },
+	/// A collection of length `size` would have been created, which is larger than `max`. Lengths
+	/// are in bytes for `Text`, and in elements for everything else.
+	TooLarge { size: usize, max: usize },
 	Messaged(String)
 }
 
@@ -18,7 +26,32 @@ impl Display for ValueError {
 		match self {
 			ValueError::BadValue { expected, got } => 
 				write!(f, "expected type '{}' but got type '{}'", expected, got),
+			ValueError::DivisionByZero(dividend) => write!(f, "division by zero: {} / 0", dividend),
+			ValueError::StackOverflow { max_depth } =>
+				write!(f, "stack overflow: maximum depth of {} exceeded", max_depth),
+			ValueError::TooLarge { size, max } =>
+				write!(f, "result too large: a length of {} exceeds the maximum of {}", size, max),
 			ValueError::Messaged(msg) => Display::fmt(&msg, f),
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn eq() {
+		let bad_value = ValueError::BadValue { expected: "1".into(), got: "2".into() };
+		assert_eq!(bad_value, ValueError::BadValue { expected: "1".into(), got: "2".into() });
+		assert_ne!(bad_value, ValueError::BadValue { expected: "1".into(), got: "3".into() });
+
+		let div_by_zero = ValueError::DivisionByZero(Number::ONE);
+		assert_eq!(div_by_zero, ValueError::DivisionByZero(Number::ONE));
+		assert_ne!(div_by_zero, ValueError::DivisionByZero(Number::ZERO));
+
+		assert_eq!(ValueError::Messaged("a".into()), ValueError::Messaged("a".into()));
+		assert_ne!(ValueError::Messaged("a".into()), ValueError::Messaged("b".into()));
+		assert_ne!(ValueError::Messaged("a".into()), ValueError::DivisionByZero(Number::ONE));
+	}
+}
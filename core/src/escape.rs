@@ -0,0 +1,189 @@
+//! Decoding the escape sequences within quoted text.
+//!
+//! This is shared by the tokenizer's text literals and [`json::parse`](crate::json::parse),
+//! which accept slightly different escapes; see [`Syntax`].
+
+use std::fmt::{self, Display, Formatter};
+
+/// Which flavour of escape sequences to accept.
+///
+/// Both accept `\\`, `\"`, `\n`, `\t` and `\r`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Syntax {
+	/// Quest's text literals, which also accept `\'`, `\0`, `\u{X...}` with one to six hex
+	/// digits, and an escaped newline (which is removed).
+	Quest,
+	/// JSON strings, which also accept `\/`, `\b`, `\f` and `\uXXXX`, where a UTF-16 surrogate
+	/// pair is written as two consecutive `\uXXXX` escapes.
+	Json
+}
+
+/// A problem decoding an escape sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error<E> {
+	/// Reading the next character failed.
+	Read(E),
+	/// The input ended partway through the escape.
+	Unterminated,
+	/// The character after the `\` isn't a known escape.
+	BadEscape(char),
+	/// A unicode escape was malformed; this contains the hex digits (and the first bad character)
+	/// that were read.
+	BadUnicode(String),
+	/// A JSON `\uXXXX` escape was half of a surrogate pair, without the other half.
+	UnpairedSurrogate
+}
+
+impl<E: Display> Display for Error<E> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Error::Read(err) => Display::fmt(err, f),
+			Error::Unterminated => write!(f, "unterminated escape"),
+			Error::BadEscape(chr) => write!(f, "bad escape char `{}`", chr),
+			Error::BadUnicode(hex) => write!(f, "bad unicode escape `{}`", hex),
+			Error::UnpairedSurrogate => write!(f, "unpaired surrogate")
+		}
+	}
+}
+
+/// Decode one escape sequence, with the leading `\` already consumed.
+///
+/// `next` is called to get each following character, returning `Ok(None)` at the end of the
+/// input; nothing past the end of the escape is read. `Ok(None)` is returned for escapes that
+/// don't produce a character (i.e. Quest's escaped newlines).
+pub fn decode<E, F>(syntax: Syntax, mut next: F) -> Result<Option<char>, Error<E>>
+where
+	F: FnMut() -> Result<Option<char>, E>
+{
+	let mut next = || next().map_err(Error::Read);
+
+	let chr =
+		match (next()?.ok_or(Error::Unterminated)?, syntax) {
+			(chr @ '\\', _) | (chr @ '"', _) => chr,
+			('n', _) => '\n',
+			('t', _) => '\t',
+			('r', _) => '\r',
+			('\'', Syntax::Quest) => '\'',
+			('0', Syntax::Quest) => '\0',
+			('\n', Syntax::Quest) => return Ok(None),
+			('u', Syntax::Quest) => quest_unicode(&mut next)?,
+			('/', Syntax::Json) => '/',
+			('b', Syntax::Json) => '\u{8}',
+			('f', Syntax::Json) => '\u{c}',
+			('u', Syntax::Json) => json_unicode(&mut next)?,
+			(chr, _) => return Err(Error::BadEscape(chr))
+		};
+
+	Ok(Some(chr))
+}
+
+/// Parse the `{X...}` of a `\u{X...}` escape.
+fn quest_unicode<E>(next: &mut impl FnMut() -> Result<Option<char>, Error<E>>)
+	-> Result<char, Error<E>>
+{
+	let mut hex = String::with_capacity(6);
+
+	if next()? != Some('{') {
+		return Err(Error::BadUnicode(hex));
+	}
+
+	loop {
+		match next()? {
+			Some('}') => break,
+			Some(chr) if chr.is_ascii_hexdigit() && hex.len() < 6 => hex.push(chr),
+			Some(chr) => {
+				hex.push(chr);
+				return Err(Error::BadUnicode(hex));
+			},
+			None => return Err(Error::BadUnicode(hex))
+		}
+	}
+
+	u32::from_str_radix(&hex, 16).ok()
+		.and_then(std::char::from_u32)
+		.ok_or(Error::BadUnicode(hex))
+}
+
+/// Parse the `XXXX` of a `\uXXXX` escape, along with the second half of a surrogate pair.
+fn json_unicode<E>(next: &mut impl FnMut() -> Result<Option<char>, Error<E>>)
+	-> Result<char, Error<E>>
+{
+	let hex = |next: &mut dyn FnMut() -> Result<Option<char>, Error<E>>| {
+		let mut digits = String::with_capacity(4);
+
+		while digits.len() < 4 {
+			match next()? {
+				Some(chr) if chr.is_ascii_hexdigit() => digits.push(chr),
+				Some(chr) => {
+					digits.push(chr);
+					return Err(Error::BadUnicode(digits));
+				},
+				None => return Err(Error::BadUnicode(digits))
+			}
+		}
+
+		Ok(u32::from_str_radix(&digits, 16).expect("already checked they're hex digits"))
+	};
+
+	let high = hex(next)?;
+
+	let code =
+		if (0xd800..0xdc00).contains(&high) {
+			if next()? != Some('\\') || next()? != Some('u') {
+				return Err(Error::UnpairedSurrogate);
+			}
+
+			let low = hex(next)?;
+			if !(0xdc00..0xe000).contains(&low) {
+				return Err(Error::UnpairedSurrogate);
+			}
+
+			0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+		} else {
+			high
+		};
+
+	std::char::from_u32(code).ok_or(Error::UnpairedSurrogate)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn decode_str(syntax: Syntax, src: &str) -> Result<Option<char>, Error<!>> {
+		let mut chars = src.chars();
+		decode(syntax, || Ok(chars.next()))
+	}
+
+	#[test]
+	fn shared() {
+		for &syntax in &[Syntax::Quest, Syntax::Json] {
+			assert_eq!(decode_str(syntax, "n"), Ok(Some('\n')));
+			assert_eq!(decode_str(syntax, "\\"), Ok(Some('\\')));
+			assert_eq!(decode_str(syntax, "\""), Ok(Some('"')));
+			assert_eq!(decode_str(syntax, "q"), Err(Error::BadEscape('q')));
+			assert_eq!(decode_str(syntax, ""), Err(Error::Unterminated));
+		}
+	}
+
+	#[test]
+	fn quest() {
+		assert_eq!(decode_str(Syntax::Quest, "'"), Ok(Some('\'')));
+		assert_eq!(decode_str(Syntax::Quest, "\n"), Ok(None));
+		assert_eq!(decode_str(Syntax::Quest, "u{1F600}"), Ok(Some('\u{1F600}')));
+		assert_eq!(decode_str(Syntax::Quest, "u{110000}"), Err(Error::BadUnicode("110000".into())));
+		assert_eq!(decode_str(Syntax::Quest, "u{4g}"), Err(Error::BadUnicode("4g".into())));
+		assert_eq!(decode_str(Syntax::Quest, "/"), Err(Error::BadEscape('/')));
+	}
+
+	#[test]
+	fn json() {
+		assert_eq!(decode_str(Syntax::Json, "/"), Ok(Some('/')));
+		assert_eq!(decode_str(Syntax::Json, "u00e9"), Ok(Some('é')));
+		assert_eq!(decode_str(Syntax::Json, "ud83d\\ude00"), Ok(Some('\u{1F600}')));
+		assert_eq!(decode_str(Syntax::Json, "ud83d"), Err(Error::UnpairedSurrogate));
+		assert_eq!(decode_str(Syntax::Json, "ude00"), Err(Error::UnpairedSurrogate));
+		assert_eq!(decode_str(Syntax::Json, "u12"), Err(Error::BadUnicode("12".into())));
+		assert_eq!(decode_str(Syntax::Json, "'"), Err(Error::BadEscape('\'')));
+	}
+}
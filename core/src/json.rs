@@ -0,0 +1,334 @@
+//! Parsing JSON into Quest objects.
+//!
+//! Converting the other way is done with each type's `to_json` method (such as
+//! [`List::to_json`](crate::types::List::to_json)).
+
+use crate::{Object, Result, Binding};
+use crate::error::ValueError;
+use crate::escape::{self, Syntax};
+use crate::types::{Number, Text, List, Map, Boolean};
+use std::convert::TryFrom;
+
+/// Parse `src` as JSON.
+///
+/// Objects become [`Map`]s (with [`Text`] keys), arrays become [`List`]s, and numbers are
+/// parsed with [`Number::try_from`].
+///
+/// # Errors
+/// A [`ValueError`] (that includes the byte offset of the problem) is returned if `src` isn't
+/// valid JSON.
+pub fn parse(src: &str) -> Result<Object> {
+	let mut parser = Parser { src, pos: 0, depth: 0 };
+
+	let value = parser.value()?;
+	parser.skip_whitespace();
+
+	if parser.pos == src.len() {
+		Ok(value)
+	} else {
+		Err(parser.error("trailing characters after value"))
+	}
+}
+
+struct Parser<'a> {
+	src: &'a str,
+	pos: usize,
+	depth: usize
+}
+
+impl Parser<'_> {
+	fn error(&self, msg: &str) -> crate::Error {
+		ValueError::Messaged(format!("invalid JSON at byte {}: {}", self.pos, msg)).into()
+	}
+
+	fn peek(&self) -> Option<u8> {
+		self.src.as_bytes().get(self.pos).copied()
+	}
+
+	fn skip_whitespace(&mut self) {
+		while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+			self.pos += 1;
+		}
+	}
+
+	fn expect(&mut self, byte: u8) -> Result<()> {
+		if self.peek() == Some(byte) {
+			self.pos += 1;
+			Ok(())
+		} else {
+			Err(self.error(&format!("expected `{}`", byte as char)))
+		}
+	}
+
+	fn value(&mut self) -> Result<Object> {
+		self.skip_whitespace();
+
+		match self.peek() {
+			Some(b'n') => self.keyword("null", Object::default()),
+			Some(b't') => self.keyword("true", Boolean::TRUE.into()),
+			Some(b'f') => self.keyword("false", Boolean::FALSE.into()),
+			Some(b'"') => self.string().map(Object::from),
+			Some(b'[') => self.array(),
+			Some(b'{') => self.object(),
+			Some(b'-') | Some(b'0'..=b'9') => self.number(),
+			Some(_) => Err(self.error("expected a value")),
+			None => Err(self.error("unexpected end of input"))
+		}
+	}
+
+	fn keyword(&mut self, keyword: &str, value: Object) -> Result<Object> {
+		if self.src[self.pos..].starts_with(keyword) {
+			self.pos += keyword.len();
+			Ok(value)
+		} else {
+			Err(self.error("expected a value"))
+		}
+	}
+
+	fn number(&mut self) -> Result<Object> {
+		let start = self.pos;
+
+		if self.peek() == Some(b'-') {
+			self.pos += 1;
+		}
+
+		let digits = |parser: &mut Self| {
+			let start = parser.pos;
+			while let Some(b'0'..=b'9') = parser.peek() {
+				parser.pos += 1;
+			}
+			parser.pos - start
+		};
+
+		match digits(self) {
+			0 => return Err(self.error("expected a digit")),
+			len if len > 1 && self.src.as_bytes()[self.pos - len] == b'0' =>
+				return Err(self.error("numbers can't have leading zeros")),
+			_ => {}
+		}
+
+		if self.peek() == Some(b'.') {
+			self.pos += 1;
+			if digits(self) == 0 {
+				return Err(self.error("expected a digit after `.`"));
+			}
+		}
+
+		if let Some(b'e') | Some(b'E') = self.peek() {
+			self.pos += 1;
+			if let Some(b'+') | Some(b'-') = self.peek() {
+				self.pos += 1;
+			}
+			if digits(self) == 0 {
+				return Err(self.error("expected a digit in the exponent"));
+			}
+		}
+
+		Number::try_from(&self.src[start..self.pos])
+			.map(Object::from)
+			.map_err(|err| self.error(&err.to_string()))
+	}
+
+	fn string(&mut self) -> Result<Text> {
+		self.expect(b'"')?;
+		let mut text = String::new();
+
+		loop {
+			let chr = self.src[self.pos..].chars().next()
+				.ok_or_else(|| self.error("unterminated string"))?;
+			self.pos += chr.len_utf8();
+
+			match chr {
+				'"' => return Ok(text.into()),
+				'\\' => text.push(self.escape()?),
+				chr if (chr as u32) < 0x20 => {
+					self.pos -= 1;
+					return Err(self.error("unescaped control character in string"));
+				},
+				chr => text.push(chr)
+			}
+		}
+	}
+
+	/// Parse an escape, with the leading `\` already consumed.
+	fn escape(&mut self) -> Result<char> {
+		let decoded = escape::decode(Syntax::Json, || -> std::result::Result<_, !> {
+			let chr = self.src[self.pos..].chars().next();
+			self.pos += chr.map_or(0, char::len_utf8);
+			Ok(chr)
+		});
+
+		match decoded {
+			Ok(chr) => Ok(chr.expect("json escapes always produce a char")),
+			Err(err) => Err(self.error(&err.to_string()))
+		}
+	}
+
+	/// Track entering an array or object, erroring if they're nested more than
+	/// [`Binding::max_depth`] deep.
+	fn nest(&mut self) -> Result<()> {
+		let max_depth = Binding::max_depth();
+		self.depth += 1;
+
+		if self.depth <= max_depth {
+			Ok(())
+		} else {
+			Err(self.error(&format!("arrays and objects nested more than {} deep", max_depth)))
+		}
+	}
+
+	fn array(&mut self) -> Result<Object> {
+		self.expect(b'[')?;
+		self.nest()?;
+		let mut list = vec![];
+
+		self.skip_whitespace();
+		if self.peek() == Some(b']') {
+			self.pos += 1;
+			self.depth -= 1;
+			return Ok(List::from(list).into());
+		}
+
+		loop {
+			list.push(self.value()?);
+			self.skip_whitespace();
+
+			match self.peek() {
+				Some(b',') => self.pos += 1,
+				Some(b']') => {
+					self.pos += 1;
+					self.depth -= 1;
+					return Ok(List::from(list).into())
+				},
+				_ => return Err(self.error("expected `,` or `]`"))
+			}
+		}
+	}
+
+	fn object(&mut self) -> Result<Object> {
+		self.expect(b'{')?;
+		self.nest()?;
+		let map = Map::new();
+
+		self.skip_whitespace();
+		if self.peek() == Some(b'}') {
+			self.pos += 1;
+			self.depth -= 1;
+			return Ok(map.into());
+		}
+
+		loop {
+			self.skip_whitespace();
+			let key = self.string()?;
+			self.skip_whitespace();
+			self.expect(b':')?;
+			let value = self.value()?;
+
+			map.insert(key.into(), value)?;
+			self.skip_whitespace();
+
+			match self.peek() {
+				Some(b',') => self.pos += 1,
+				Some(b'}') => {
+					self.pos += 1;
+					self.depth -= 1;
+					return Ok(map.into())
+				},
+				_ => return Err(self.error("expected `,` or `}`"))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn num(obj: &Object) -> Number {
+		*obj.downcast_ref::<Number>().expect("not a number")
+	}
+
+	fn text(obj: &Object) -> String {
+		obj.downcast_ref::<Text>().expect("not a text").to_string()
+	}
+
+	#[test]
+	fn scalars() {
+		assert!(parse("null").unwrap().is_a::<crate::types::Null>());
+		assert_eq!(*parse(" true ").unwrap().downcast_ref::<Boolean>().unwrap(), Boolean::TRUE);
+		assert_eq!(*parse("false").unwrap().downcast_ref::<Boolean>().unwrap(), Boolean::FALSE);
+		assert_eq!(num(&parse("-12").unwrap()), Number::from(-12));
+		assert_eq!(num(&parse("2.5e-1").unwrap()), Number::from(0.25));
+		assert_eq!(num(&parse("1e300").unwrap()), Number::from(1e300));
+		assert_eq!(text(&parse(r#""a\"b\\c\n\u00e9\ud83d\ude00""#).unwrap()), "a\"b\\c\né\u{1f600}");
+	}
+
+	#[test]
+	fn arrays() {
+		let list = parse("[1, [2, []], \"x\"]").unwrap();
+		let list = list.downcast_ref::<List>().unwrap();
+
+		assert_eq!(list.len(), 3);
+		assert_eq!(num(&list.get(0)), Number::from(1));
+		assert_eq!(text(&list.get(2)), "x");
+
+		let inner = list.get(1);
+		let inner = inner.downcast_ref::<List>().unwrap();
+		assert_eq!(num(&inner.get(0)), Number::from(2));
+		assert!(inner.get(1).downcast_ref::<List>().unwrap().is_empty());
+	}
+
+	#[test]
+	fn objects() {
+		let map = parse(r#"{"a": 1, "b": {"c": [true]}, "a": 2}"#).unwrap();
+		let map = map.downcast_ref::<Map>().unwrap();
+
+		assert_eq!(map.len(), 2);
+		assert_eq!(num(&map.get(&Object::from("a")).unwrap().unwrap()), Number::from(2));
+
+		let b = map.get(&Object::from("b")).unwrap().unwrap();
+		let c = b.downcast_ref::<Map>().unwrap().get(&Object::from("c")).unwrap().unwrap();
+		assert_eq!(c.downcast_ref::<List>().unwrap().len(), 1);
+
+		assert!(parse("{}").unwrap().downcast_ref::<Map>().unwrap().is_empty());
+	}
+
+	#[test]
+	fn round_trip() {
+		const JSON: &str = r#"[1,-2.5,"a\"\n\u0001",[true,false,null,[]]]"#;
+
+		let list = parse(JSON).unwrap();
+		assert_eq!(list.downcast_ref::<List>().unwrap().to_json().unwrap(), JSON);
+	}
+
+	#[test]
+	fn malformed() {
+		let err = |src| match parse(src).unwrap_err() {
+			crate::Error::ValueError(ValueError::Messaged(msg)) => msg,
+			err => panic!("unexpected error: {:?}", err)
+		};
+
+		assert_eq!(err("[1, 2"), "invalid JSON at byte 5: expected `,` or `]`");
+		assert_eq!(err("{\"a\" 1}"), "invalid JSON at byte 5: expected `:`");
+		assert_eq!(err("[1] 2"), "invalid JSON at byte 4: trailing characters after value");
+		assert_eq!(err("01"), "invalid JSON at byte 2: numbers can't have leading zeros");
+		assert!(err("").contains("unexpected end of input"));
+		assert!(err("nul").contains("expected a value"));
+		assert!(err("\"abc").contains("unterminated string"));
+		assert!(err("\"\\x\"").contains("bad escape"));
+		assert!(err("\"\\ud83d\"").contains("unpaired surrogate"));
+		assert!(err("[1,]").contains("expected a value"));
+		assert!(err("1.").contains("expected a digit"));
+		assert!(err("\"\\u12\"").contains("bad unicode escape"));
+	}
+
+	#[test]
+	fn deeply_nested() {
+		let nested = |depth| "[".repeat(depth) + &"]".repeat(depth);
+
+		assert!(parse(&nested(Binding::max_depth())).is_ok());
+		assert!(parse(&nested(Binding::max_depth() + 1)).is_err());
+		assert!(parse(&"[".repeat(200_000)).is_err());
+		assert!(parse(&"{\"a\":".repeat(200_000)).is_err());
+	}
+}
@@ -22,6 +22,8 @@ pub fn init() {
 }
 
 pub mod utils;
+pub mod escape;
+pub mod json;
 pub mod obj;
 pub mod types;
 pub mod literals;
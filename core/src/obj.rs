@@ -5,7 +5,8 @@ use crate::literals::Literal;
 
 use std::borrow::Borrow;
 use std::hash::Hash;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock, OnceLock};
 use std::fmt::{self, Debug, Formatter};
 use std::any::Any;
 use std::ops::{Deref, DerefMut};
@@ -31,6 +32,8 @@ impl Default for Object {
 pub(super) struct Internal {
 	attrs: Attributes,
 	data: Data,
+	// most objects never have observers, so they're only allocated once one is added.
+	observers: OnceLock<Box<RwLock<Vec<types::RustFn>>>>,
 }
 
 impl Debug for Object {
@@ -95,7 +98,7 @@ impl Object {
 
 	#[inline]
 	fn from_parts(data: Data, attrs: Attributes) -> Self {
-		Object(Arc::new(Internal { data, attrs }))
+		Object(Arc::new(Internal { data, attrs, observers: OnceLock::new() }))
 	}
 
 	#[inline]
@@ -118,6 +121,12 @@ impl Object {
 		self.0.data.typename()
 	}
 
+	/// Like [`typename`](Self::typename), but without the module path (eg `Text`).
+	#[inline]
+	pub fn short_typename(&self) -> &'static str {
+		crate::utils::short_type_name(self.typename())
+	}
+
 	#[inline]
 	pub fn is_identical(&self, rhs: &Object) -> bool {
 		Arc::ptr_eq(&self.0, &rhs.0)
@@ -130,8 +139,83 @@ impl Object {
 				.unwrap_or(false))
 	}
 
+	/// Create a new object with its own copy of this object's data and attributes.
+	///
+	/// Other objects that this one refers to (via its attributes, parents, or elements if it's a
+	/// [`List`](types::List)) are shared, not cloned, unless they refer back to this object.
+	/// Every object on such a cycle is cloned once, and references between them are pointed at
+	/// the clones, so cycles are preserved rather than leading back to the originals.
+	///
+	/// Objects that are only reachable through parents (usually classes) aren't searched for
+	/// cycles, but a parent that's on a cycle found some other way is still replaced.
 	pub fn deep_clone(&self) -> Object {
-		Object::from_parts(self.0.data.clone(), self.0.attrs.clone())
+		let mut clones = self.cycle_members()
+			.into_iter()
+			.map(|obj| (obj.id(), Object::from_parts(obj.0.data.clone(), obj.0.attrs.clone())))
+			.collect::<HashMap<_, _>>();
+
+		for clone in clones.values() {
+			clone.replace_cloned(&clones);
+		}
+
+		clones.remove(&self.id()).expect("an object is always on its own cycle")
+	}
+
+	/// Find every object that's reachable from this one and refers back to it, including itself.
+	fn cycle_members(&self) -> Vec<Object> {
+		// every object we've searched, and the ids of the objects it refers to.
+		let mut searched = HashMap::<usize, (Object, Vec<usize>)>::new();
+		let mut to_search = vec![self.clone()];
+
+		while let Some(obj) = to_search.pop() {
+			if searched.contains_key(&obj.id()) {
+				continue;
+			}
+
+			let mut children = obj.0.attrs.attr_objects();
+			if let Some(list) = obj.downcast_ref::<types::List>() {
+				children.extend(list.iter().cloned());
+			}
+
+			let refers_to = children.iter()
+				.chain(&obj.0.attrs.parent_objects())
+				.map(Object::id)
+				.collect();
+
+			to_search.extend(children);
+			searched.insert(obj.id(), (obj, refers_to));
+		}
+
+		let mut referrers = HashMap::<usize, Vec<usize>>::new();
+		for (&id, (_, refers_to)) in searched.iter() {
+			for &child in refers_to {
+				referrers.entry(child).or_default().push(id);
+			}
+		}
+
+		let mut members = HashSet::new();
+		let mut to_mark = vec![self.id()];
+
+		while let Some(id) = to_mark.pop() {
+			if members.insert(id) {
+				to_mark.extend(referrers.get(&id).into_iter().flatten().copied());
+			}
+		}
+
+		members.into_iter().map(|id| searched.remove(&id).expect("only searched objects refer to others").0).collect()
+	}
+
+	/// Point references to objects in `clones` (by id) at their clones instead.
+	fn replace_cloned(&self, clones: &HashMap<usize, Object>) {
+		self.0.attrs.replace_cloned(clones);
+
+		let replaced = self.downcast_ref::<types::List>()
+			.filter(|list| list.iter().any(|ele| clones.contains_key(&ele.id())))
+			.map(|list| list.iter().map(|ele| clones.get(&ele.id()).unwrap_or(ele).clone()).collect::<Vec<_>>());
+
+		if let Some(replaced) = replaced {
+			*self.downcast_mut::<types::List>().expect("it was just a list") = replaced.into();
+		}
 	}
 }
 
@@ -217,14 +301,35 @@ impl Object {
 			.ok_or_else(|| KeyError::DoesntExist { attr: attr.to_object(), obj: self.clone() }.into())
 	}
 
+	/// Set `attr` without it being able to fail.
+	///
+	/// Observers are still notified, but as this is used for internal bookkeeping (such as setting
+	/// up classes and stackframes), any errors they return are ignored rather than aborting the
+	/// write; use [`set_attr`](Object::set_attr) if they should be able to.
 	pub fn set_attr_lit<V: Into<Value>>(&self, attr: Literal, value: V) {
-		self.0.attrs.set_lit(attr, value.into())
+		let value = value.into();
+
+		if self.has_observers() {
+			let old = self.get_value_lit(attr).ok().flatten().map(Object::from);
+			let _ = self.notify_observers(&attr.to_object(), Some(value.clone().into()), old);
+		}
+
+		self.0.attrs.set_lit(attr, value)
 	}
 
-	pub fn del_attr_lit<K: Hash + Eq + ?Sized>(&self, attr: &K) -> Option<Value>
+	/// Delete `attr` without it being able to fail, ignoring observers' errors like
+	/// [`set_attr_lit`](Object::set_attr_lit) does.
+	pub fn del_attr_lit<K>(&self, attr: &K) -> Option<Value>
 	where
 		for <'a> &'a str: Borrow<K>,
+		K: Hash + Eq + ToObject + ?Sized
 	{
+		if self.has_observers() {
+			if let Some(old) = self.get_value_lit(attr).ok().flatten() {
+				let _ = self.notify_observers(&attr.to_object(), None, Some(old.into()));
+			}
+		}
+
 		self.0.attrs.del_lit(attr)
 	}
 
@@ -239,6 +344,42 @@ impl Object {
 			.call(self, args.into())
 	}
 
+	/// Register `observer` to be called before an attribute of this object is changed, whether
+	/// that's via [`set_attr`](Object::set_attr), [`del_attr`](Object::del_attr), their `_lit`
+	/// versions, or [`add_parent`](Object::add_parent) (which changes `__parents__`).
+	///
+	/// Observers are called in the order they were added, with this object as `this`, and with
+	/// the key, the new value (`null` when deleting) and the old value (`null` if there wasn't one)
+	/// as arguments. If an observer returns an error, the attribute is left unchanged (except for
+	/// the `_lit` versions, which ignore it).
+	///
+	/// Observers aren't copied by [`deep_clone`](Object::deep_clone).
+	pub fn add_observer(&self, observer: types::RustFn) {
+		self.0.observers.get_or_init(Box::default).write().expect("observers poisoned").push(observer);
+	}
+
+	fn has_observers(&self) -> bool {
+		self.0.observers.get()
+			.is_some_and(|observers| !observers.read().expect("observers poisoned").is_empty())
+	}
+
+	fn notify_observers(&self, attr: &Object, new: Option<Object>, old: Option<Object>) -> Result<()> {
+		// clone them so observers are free to add more observers.
+		let observers =
+			match self.0.observers.get() {
+				Some(observers) => observers.read().expect("observers poisoned").clone(),
+				None => return Ok(())
+			};
+
+		let (new, old) = (new.unwrap_or_default(), old.unwrap_or_default());
+
+		for observer in observers {
+			observer.call(self, Args::new(vec![attr, &new, &old]))?;
+		}
+
+		Ok(())
+	}
+
 	pub fn has_attr(&self, attr: &Object) -> Result<bool> {
 		self.0.attrs.has(attr)
 	}
@@ -254,10 +395,23 @@ impl Object {
 	}
 
 	pub fn set_attr<V: Into<Value>>(&self, attr: Object, value: V) -> Result<()> {
-		self.0.attrs.set(attr, value.into())
+		let value = value.into();
+
+		if self.has_observers() {
+			let old = self.get_value(&attr)?.map(Object::from);
+			self.notify_observers(&attr, Some(value.clone().into()), old)?;
+		}
+
+		self.0.attrs.set(attr, value)
 	}
 
 	pub fn del_attr(&self, attr: &Object) -> Result<Object> {
+		if self.has_observers() {
+			if let Some(old) = self.get_value(attr)? {
+				self.notify_observers(attr, None, Some(old.into()))?;
+			}
+		}
+
 		self.0.attrs.del(attr)?
 			.map(Object::from)
 			.ok_or_else(|| KeyError::DoesntExist { attr: attr.to_object(), obj: self.clone() }.into())
@@ -316,11 +470,235 @@ impl Object {
 
 	#[inline]
 	pub fn add_parent(&self, val: Object) -> Result<()> {
+		if self.has_observers() {
+			let old = self.0.attrs.parents()?;
+			let new = old.iter().cloned().chain(std::iter::once(val.clone())).collect::<Vec<_>>();
+
+			self.notify_observers(&crate::literals::__PARENTS__.to_object(), Some(new.into()), Some(old.into()))?;
+		}
+
 		self.0.attrs.add_parent(val)
 	}
 
+	/// Checks to see if `ancestor` is anywhere in this object's parent chain.
+	///
+	/// Each object is only visited once, so cyclic parent chains are fine.
+	pub fn is_descendant_of(&self, ancestor: &Object) -> Result<bool> {
+		let mut seen = vec![self.id()];
+		let mut queue = self.0.attrs.parents()?;
+
+		while let Some(parent) = queue.pop() {
+			if parent.is_identical(ancestor) {
+				return Ok(true);
+			}
+
+			if !seen.contains(&parent.id()) {
+				seen.push(parent.id());
+				queue.extend(parent.0.attrs.parents()?);
+			}
+		}
+
+		Ok(false)
+	}
+
+	/// Get the keys of this object, optionally including its parents.
+	///
+	/// Keys are normally in an unspecified order; pass `sorted` to get a deterministic one.
 	#[inline]
-	pub fn mapping_keys(&self, include_parents: bool) -> Result<Vec<Object>> {
-		self.0.attrs.keys(include_parents)
+	pub fn mapping_keys(&self, include_parents: bool, sorted: bool) -> Result<Vec<Object>> {
+		self.0.attrs.keys(include_parents, sorted)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parents_of(obj: &Object) -> Vec<Object> {
+		obj.get_attr_lit("__parents__").unwrap().downcast_call::<types::List>().unwrap().into_iter().collect()
+	}
+
+	#[test]
+	fn short_typename() {
+		assert_eq!(Object::from(1).short_typename(), "Number");
+		assert_eq!(Object::from("a").short_typename(), "Text");
+		assert_eq!(crate::utils::short_type_name("alloc::vec::Vec<alloc::string::String>"), "Vec<alloc::string::String>");
+	}
+
+	#[test]
+	fn observers() {
+		let obj = Object::from(1);
+		let log = Arc::new(std::sync::Mutex::new(vec![]));
+
+		for name in &["first", "second"] {
+			let log = log.clone();
+			obj.add_observer(types::RustFn::new_boxed("observer", move |this, args| {
+				let text = |idx| args.arg(idx).unwrap().downcast_call::<types::Text>().unwrap().to_string();
+				log.lock().unwrap().push((*name, this.id(), text(0), text(1), text(2)));
+				Ok(Object::default())
+			}));
+		}
+
+		let key = Object::from("x");
+		obj.set_attr(key.clone(), Object::from(2)).unwrap();
+		obj.set_attr(key.clone(), Object::from(3)).unwrap();
+		obj.del_attr(&key).unwrap();
+
+		let entry = |name, new: &str, old: &str| (name, obj.id(), "x".to_string(), new.to_string(), old.to_string());
+		assert_eq!(*log.lock().unwrap(), vec![
+			entry("first", "2", "null"), entry("second", "2", "null"),
+			entry("first", "3", "2"), entry("second", "3", "2"),
+			entry("first", "null", "3"), entry("second", "null", "3"),
+		]);
+	}
+
+	#[test]
+	fn observers_see_every_write() {
+		let obj = Object::new_with_parent(types::Basic, ());
+		let log = Arc::new(std::sync::Mutex::new(vec![]));
+
+		{
+			let log = log.clone();
+			obj.add_observer(types::RustFn::new_boxed("observer", move |_, args| {
+				log.lock().unwrap().push(args.arg(0)?.downcast_call::<types::Text>()?.to_string());
+				Ok(Object::default())
+			}));
+		}
+
+		obj.set_attr_lit("x", Object::from(1));
+		assert!(obj.del_attr_lit("x").is_some());
+		obj.add_parent(Object::from(2)).unwrap();
+
+		assert_eq!(*log.lock().unwrap(), vec!["x", "x", "__parents__"]);
+	}
+
+	#[test]
+	fn observers_cant_abort_lit_writes() {
+		let obj = Object::new_with_parent(types::Basic, ());
+		obj.add_observer(types::RustFn::new_boxed("observer", |_, _|
+			Err(crate::error::ValueError::Messaged("read only".into()).into())));
+
+		obj.set_attr_lit("x", Object::from(2));
+		assert_eq!(*obj.get_attr_lit("x").unwrap().downcast_ref::<types::Number>().unwrap(), types::Number::from(2));
+		assert!(obj.del_attr_lit("x").is_some());
+		assert!(!obj.has_attr_lit("x").unwrap());
+
+		assert!(obj.add_parent(Object::from(3)).is_err());
+		assert!(parents_of(&obj).is_empty());
+	}
+
+	#[test]
+	fn observer_errors_abort() {
+		let obj = Object::from(1);
+		let key = Object::from("x");
+		obj.set_attr(key.clone(), Object::from(2)).unwrap();
+
+		obj.add_observer(types::RustFn::new("observer", |_, _| Err("read only".to_string().into())));
+
+		assert!(obj.set_attr(key.clone(), Object::from(3)).is_err());
+		assert!(obj.del_attr(&key).is_err());
+		assert_eq!(obj.get_attr(&key).unwrap().downcast_call::<types::Number>().unwrap(), 2.into());
+	}
+
+	#[test]
+	fn is_descendant_of() {
+		let grandparent = Object::new_with_parent(types::Basic, ());
+		let parent = Object::new_with_parent(types::Basic, vec![grandparent.clone()]);
+		let child = Object::new_with_parent(types::Basic, vec![parent.clone()]);
+		let unrelated = Object::new_with_parent(types::Basic, ());
+
+		assert!(child.is_descendant_of(&parent).unwrap());
+		assert!(child.is_descendant_of(&grandparent).unwrap());
+		assert!(!child.is_descendant_of(&unrelated).unwrap());
+		assert!(!child.is_descendant_of(&child).unwrap());
+		assert!(!grandparent.is_descendant_of(&child).unwrap());
+
+		// cycles don't loop forever
+		grandparent.add_parent(child.clone()).unwrap();
+		assert!(child.is_descendant_of(&child).unwrap());
+		assert!(!child.is_descendant_of(&unrelated).unwrap());
+	}
+
+	#[test]
+	fn deep_clone_shares_other_objects() {
+		let (obj, attr) = (Object::from(1), Object::from(2));
+		obj.set_attr_lit("attr", attr.clone());
+
+		let clone = obj.deep_clone();
+
+		assert!(!clone.is_identical(&obj));
+		assert_eq!(*clone.downcast_ref::<types::Number>().unwrap(), types::Number::from(1));
+		assert!(clone.get_attr_lit("attr").unwrap().is_identical(&attr));
+	}
+
+	#[test]
+	fn deep_clone_preserves_cycles() {
+		let obj = Object::new_with_parent(types::Basic, ());
+		obj.set_attr_lit("me", obj.clone());
+		obj.add_parent(obj.clone()).unwrap();
+
+		let clone = obj.deep_clone();
+
+		assert!(clone.get_attr_lit("me").unwrap().is_identical(&clone));
+		let parents = parents_of(&clone);
+		assert_eq!(parents.len(), 1);
+		assert!(parents[0].is_identical(&clone));
+
+		// the original is left alone
+		assert!(obj.get_attr_lit("me").unwrap().is_identical(&obj));
+		assert!(parents_of(&obj)[0].is_identical(&obj));
+	}
+
+	#[test]
+	fn deep_clone_preserves_cycles_through_parent_lists() {
+		let obj = Object::new_with_parent(types::Basic, ());
+		let parents = Object::from(vec![Object::from(1), obj.clone()]);
+		obj.set_attr_lit("__parents__", parents.clone());
+
+		let clone = obj.deep_clone();
+		let clone_parents = parents_of(&clone);
+
+		assert_eq!(clone_parents.len(), 2);
+		assert!(clone_parents[1].is_identical(&clone));
+		assert!(parents.downcast_ref::<types::List>().unwrap().get(1).is_identical(&obj));
+	}
+
+	#[test]
+	fn deep_clone_preserves_indirect_cycles() {
+		// a.b = b; b.a = a; b.list = [a]; c's parent is a, and a.c = c
+		let (a, b, c) = (
+			Object::new_with_parent(types::Basic, ()),
+			Object::new_with_parent(types::Basic, ()),
+			Object::new_with_parent(types::Basic, ())
+		);
+		let (shared, list) = (Object::from(1), Object::from(vec![a.clone()]));
+
+		a.set_attr_lit("b", b.clone());
+		a.set_attr_lit("shared", shared.clone());
+		b.set_attr_lit("a", a.clone());
+		b.set_attr_lit("list", list.clone());
+		c.add_parent(a.clone()).unwrap();
+		a.set_attr_lit("c", c.clone());
+
+		let a2 = a.deep_clone();
+		let b2 = a2.get_attr_lit("b").unwrap();
+		let list2 = b2.get_attr_lit("list").unwrap();
+		let c2 = a2.get_attr_lit("c").unwrap();
+
+		assert!(!b2.is_identical(&b));
+		assert!(b2.get_attr_lit("a").unwrap().is_identical(&a2));
+		assert!(!list2.is_identical(&list));
+		assert!(list2.downcast_ref::<types::List>().unwrap().get(0).is_identical(&a2));
+		assert!(!c2.is_identical(&c));
+		assert!(parents_of(&c2)[0].is_identical(&a2));
+
+		// objects that don't refer back to `a` are shared
+		assert!(a2.get_attr_lit("shared").unwrap().is_identical(&shared));
+
+		// the originals are left alone
+		assert!(a.get_attr_lit("b").unwrap().is_identical(&b));
+		assert!(b.get_attr_lit("a").unwrap().is_identical(&a));
+		assert!(list.downcast_ref::<types::List>().unwrap().get(0).is_identical(&a));
+		assert!(parents_of(&c)[0].is_identical(&a));
 	}
 }
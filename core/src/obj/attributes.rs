@@ -5,6 +5,7 @@ use crate::literals::{__PARENTS__, __ID__, __ATTR_MISSING__};
 use crate::{Object, Result};
 use crate::types::Text;
 use std::hash::Hash;
+use std::collections::HashMap;
 use crate::SharedCow;
 use std::borrow::Borrow;
 
@@ -54,18 +55,58 @@ impl Attributes {
 		self.id
 	}
 
+	/// Every object held directly in the attributes, as keys or values. This doesn't include
+	/// parents; see [`parent_objects`](Attributes::parent_objects) for those.
+	pub fn attr_objects(&self) -> Vec<Object> {
+		self.data.with_ref(|inner| inner.map.referenced().cloned().collect())
+	}
+
+	/// The parents, along with the list holding them (if there is one).
+	pub fn parent_objects(&self) -> Vec<Object> {
+		self.data.with_ref(|inner| inner.parents.referenced())
+	}
+
+	/// Replace every reference in the attributes and parents to an object whose id is in `clones`
+	/// with the object it maps to.
+	///
+	/// The underlying data is only copied if there's actually something to replace.
+	pub fn replace_cloned(&self, clones: &HashMap<usize, Object>) {
+		let references = self.attr_objects().into_iter()
+			.chain(self.parent_objects())
+			.any(|obj| clones.contains_key(&obj.id()));
+
+		if references {
+			self.data.with_mut(|inner| {
+				inner.map.replace_cloned(clones);
+				inner.parents.replace_cloned(clones);
+			});
+		}
+	}
+
 	pub fn add_parent(&self, parent: Object) -> Result<()> {
 		self.data.with_mut(|inner| inner.parents.add_parent(parent))
 	}
 
-	pub fn keys(&self, include_parents: bool) -> Result<Vec<Object>> {
+	/// Get the direct parents, in order.
+	pub fn parents(&self) -> Result<Vec<Object>> {
+		self.data.with_ref(|inner| inner.parents.keys())
+	}
+
+	/// Get all the keys, optionally including parents.
+	///
+	/// If `sorted` is given, literal keys are sorted lexically so that the order is deterministic.
+	pub fn keys(&self, include_parents: bool, sorted: bool) -> Result<Vec<Object>> {
 		let mut keys = vec![];
 
 		keys.push(__PARENTS__.into());
 		keys.push(__ID__.into());
 
 		let x: Result<()> = self.data.with_ref(|inner| {
-			keys.extend(inner.map.keys());
+			if sorted {
+				keys.extend(inner.map.keys_sorted());
+			} else {
+				keys.extend(inner.map.keys());
+			}
 			if include_parents {
 				keys.extend(inner.parents.keys()?);
 			}
@@ -156,7 +197,8 @@ impl Attributes {
 
 	pub fn set(&self, key: Object, value: Value) -> Result<()> {
 		if let Some(text) = key.downcast_ref::<Text>() {
-			return Ok(self.set_lit(str_to_static(text.as_ref()), value));
+			self.set_lit(crate::utils::intern(text.as_ref()), value);
+			return Ok(());
 		}
 
 		self.data.with_mut(|inner| inner.map.set_obj(key, value))
@@ -171,27 +213,3 @@ impl Attributes {
 	}
 }
 
-fn str_to_static(key: &str) -> &'static str {
-	use std::collections::HashSet;
-	use std::cell::RefCell;
-
-	thread_local! {
-		// a list of strings that have been converted so far; this is to improve efficiency.
-		static STATIC_STRS: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
-	}
-
-
-	STATIC_STRS.with(|set| {
-		if let Some(static_key) = set.borrow().get(key) {
-			return *static_key;
-		};
-
-		// leak the string to turn it static.
-		set.borrow_mut().insert(Box::leak(key.to_string().into_boxed_str()));
-		match set.borrow().get(key) {
-			Some(key) => *key,
-			None => unreachable!("we just inserted this?")
-		}
-	})
-}
-
@@ -1,6 +1,6 @@
 use std::borrow::Borrow;
 use crate::{Object, Result};
-use crate::types::{RustFn, Text, Boolean};
+use crate::types::{RustFn, Text, Boolean, Number};
 use std::hash::{self, Hash, Hasher};
 use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
@@ -37,6 +37,17 @@ impl AttrMap {
 		AttrMap::default()
 	}
 
+	/// Get the keys, with literal keys sorted lexically followed by non-literal keys in the order
+	/// they were inserted.
+	pub fn keys_sorted(&self) -> impl Iterator<Item=Object> + '_ {
+		let mut literals = self.literals.keys().collect::<Vec<_>>();
+		literals.sort_unstable();
+
+		literals.into_iter()
+			.map(|k| Object::from(*k))
+			.chain(self.objects.iter().map(|(k, _)| k.clone()))
+	}
+
 	// in the future, this can be an exact size iterator
 	pub fn keys<'a>(&'a self) -> impl Iterator<Item=Object> + 'a {
 		self.literals.keys()
@@ -73,6 +84,33 @@ impl AttrMap {
 		self.literals.remove(key)
 	}
 
+	/// Find the literal key that `key` is equal to, if any.
+	///
+	/// Non-[`Text`] keys can still compare equal to literal keys, and need to be treated as the same
+	/// key as them; otherwise, setting them would create a second entry for the same key.
+	///
+	/// Only literals with the same `hash` as `key` are compared via `==`, so this usually makes at
+	/// most two calls. Keys without a working `hash` are never equal to a literal.
+	fn find_literal(&self, key: &Object) -> Result<Option<Literal>> {
+		if self.literals.is_empty() {
+			return Ok(None);
+		}
+
+		let hash =
+			match key.call_attr_lit("hash", &[]).and_then(|hash| hash.downcast_call::<Number>()) {
+				Ok(hash) => hash,
+				Err(_) => return Ok(None)
+			};
+
+		for &lit in self.literals.keys() {
+			if crate::utils::stable_hash(&Text::new_static(lit)) == hash && eq_literal(key, lit)? {
+				return Ok(Some(lit));
+			}
+		}
+
+		Ok(None)
+	}
+
 	pub fn has_obj(&self, key: &Object) -> Result<bool> {
 		for (ref k, _) in self.objects.iter() {
 			if key.eq_obj(k)? {
@@ -80,7 +118,7 @@ impl AttrMap {
 			}
 		}
 
-		Ok(false)
+		Ok(self.find_literal(key)?.is_some())
 	}
 
 	pub fn get_obj(&self, key: &Object) -> Result<Option<&Value>> {
@@ -90,7 +128,7 @@ impl AttrMap {
 			}
 		}
 
-		Ok(None)
+		Ok(self.find_literal(key)?.and_then(|lit| self.literals.get(lit)))
 	}
 
 	pub fn set_obj(&mut self, key: Object, value: Value) -> Result<()> {
@@ -101,10 +139,42 @@ impl AttrMap {
 			}
 		}
 
-		self.objects.push((key, value));
+		if let Some(lit) = self.find_literal(&key)? {
+			self.literals.insert(lit, value);
+		} else {
+			self.objects.push((key, value));
+		}
+
 		Ok(())
 	}
 
+	/// Every key and value that's an object.
+	pub fn referenced(&self) -> impl Iterator<Item=&Object> + '_ {
+		self.literals.values()
+			.chain(self.objects.iter().map(|(_, v)| v))
+			.filter_map(|v| match v { Value::Object(o) => Some(o), Value::RustFn(_) => None })
+			.chain(self.objects.iter().map(|(k, _)| k))
+	}
+
+	/// Replace every key and value whose id is in `clones` with the object it maps to.
+	pub fn replace_cloned(&mut self, clones: &HashMap<usize, Object>) {
+		let values = self.literals.values_mut().chain(self.objects.iter_mut().map(|(_, v)| v));
+
+		for value in values {
+			if let Value::Object(obj) = value {
+				if let Some(clone) = clones.get(&obj.id()) {
+					*obj = clone.clone();
+				}
+			}
+		}
+
+		for (key, _) in self.objects.iter_mut() {
+			if let Some(clone) = clones.get(&key.id()) {
+				*key = clone.clone();
+			}
+		}
+	}
+
 	pub fn del_obj(&mut self, key: &Object) -> Result<Option<Value>> {
 		let mut stop_index = None;
 		for (i, (ref k, _)) in self.objects.iter().enumerate() {
@@ -114,7 +184,13 @@ impl AttrMap {
 			}
 		}
 
-		Ok(stop_index.map(|idx| self.objects.swap_remove(idx)).map(|x| x.1))
+		if let Some(idx) = stop_index {
+			Ok(Some(self.objects.swap_remove(idx).1))
+		} else if let Some(lit) = self.find_literal(key)? {
+			Ok(self.literals.remove(lit))
+		} else {
+			Ok(None)
+		}
 	}
 }
 
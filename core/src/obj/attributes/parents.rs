@@ -4,6 +4,7 @@ use std::hash::Hash;
 use std::borrow::Borrow;
 use std::iter::FromIterator;
 use std::sync::RwLock;
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Parents(RwLock<Inner>);
@@ -98,6 +99,64 @@ impl Parents {
 
 		Ok(())
 	}
+
+	/// Remove the first parent that's identical to `parent`, returning whether one was removed.
+	pub fn remove_parent(&mut self, parent: &Object) -> Result<bool> {
+		fn position<'a, I: IntoIterator<Item=&'a Object>>(iter: I, parent: &Object) -> Option<usize> {
+			iter.into_iter().position(|p| p.is_identical(parent))
+		}
+
+		let mut inner = self.0.write().unwrap();
+		match *inner {
+			Inner::None => Ok(false),
+			Inner::Builtin(ref mut vec) =>
+				Ok(position(vec.iter(), parent).map(|idx| vec.remove(idx)).is_some()),
+			Inner::Object(ref obj) => {
+				let mut list = obj.try_downcast_mut::<crate::types::List>()?;
+				Ok(position(list.iter(), parent).and_then(|idx| list.remove(idx)).is_some())
+			}
+		}
+	}
+
+	/// The parents, along with the object holding them (if there is one).
+	///
+	/// Unlike [`keys`](Parents::keys), this doesn't fail if the parents aren't held in a list; they
+	/// just aren't included.
+	pub fn referenced(&self) -> Vec<Object> {
+		match *self.0.read().unwrap() {
+			Inner::None => vec![],
+			Inner::Builtin(ref vec) => vec.clone(),
+			Inner::Object(ref list) => std::iter::once(list.clone())
+				.chain(list.downcast_ref::<crate::types::List>()
+					.map_or_else(Vec::new, |parents| parents.iter().cloned().collect()))
+				.collect()
+		}
+	}
+
+	/// Replace every parent whose id is in `clones` with the object it maps to.
+	///
+	/// If the parents are held in a list object that contains one of them, that list is replaced
+	/// with a copy, so the original list isn't modified.
+	pub fn replace_cloned(&mut self, clones: &HashMap<usize, Object>) {
+		let replace = |obj: &Object| clones.get(&obj.id()).unwrap_or(obj).clone();
+		let mut inner = self.0.write().unwrap();
+
+		match *inner {
+			Inner::None => {},
+			Inner::Builtin(ref mut vec) => vec.iter_mut().for_each(|p| *p = replace(p)),
+			Inner::Object(ref mut list) if clones.contains_key(&list.id()) => *list = replace(list),
+			Inner::Object(ref mut list) => {
+				let replaced = list.downcast_ref::<crate::types::List>()
+					.filter(|parents| parents.iter().any(|p| clones.contains_key(&p.id())))
+					.map(|parents| parents.iter().map(replace).collect::<Vec<_>>());
+
+				if let Some(replaced) = replaced {
+					*list = Object::from(replaced);
+				}
+			}
+		}
+	}
+
 	pub fn to_object(&self) -> Object {
 		let mut inner = self.0.write().unwrap();
 		match *inner {
@@ -124,6 +183,11 @@ impl Parents {
 		}
 	}
 
+	/// Iterate over the parents without consuming `self`.
+	pub fn iter(&self) -> Result<impl Iterator<Item=Object>> {
+		self.keys().map(Vec::into_iter)
+	}
+
 	pub fn keys(&self) -> Result<Vec<Object>> {
 		self.with_iter(|iter| Ok(iter.map(|x| x.clone()).collect()))
 	}
@@ -179,28 +243,120 @@ impl Parents {
 	}
 }
 
-// impl IntoIterator for Parents {
-// 	type Item = Object;
-// 	type IntoIter = Vec<Object> as Iterator;
-// 	fn into_iter(self) -> Self::IntoIter {
-// 		Vec::from(self).into()
-// 	}
-// }
-
-
+impl IntoIterator for Parents {
+	type Item = Object;
+	type IntoIter = std::vec::IntoIter<Object>;
+
+	/// Iterate over the parents.
+	///
+	/// If the parents are an object that can't be converted to a [`List`](crate::types::List), no
+	/// parents are yielded; use [`iter`](#method.iter) if the error is needed.
+	fn into_iter(self) -> Self::IntoIter {
+		match self.0.into_inner().unwrap() {
+			Inner::None => vec![].into_iter(),
+			Inner::Builtin(vec) => vec.into_iter(),
+			Inner::Object(object) => object.downcast_call::<crate::types::List>()
+				.map(|list| list.into_iter().collect::<Vec<_>>())
+				.unwrap_or_default()
+				.into_iter()
+		}
+	}
+}
 
+#[cfg(test)]
+mod tests {
+	use super::*;
 
+	fn assert_identical(parents: Vec<Object>, expected: &[&Object]) {
+		assert_eq!(parents.len(), expected.len(), "bad parents: {:?}", parents);
 
+		for (parent, expected) in parents.iter().zip(expected) {
+			assert!(parent.is_identical(expected), "bad parents: {:?}", parents);
+		}
+	}
 
+	fn assert_parents(parents: &Parents, expected: &[&Object]) {
+		assert_identical(parents.keys().unwrap(), expected);
+	}
 
+	#[test]
+	fn iter() {
+		let (a, b) = (Object::from(1), Object::from(2));
+
+		assert_eq!(Parents::default().iter().unwrap().count(), 0);
+		assert_identical(
+			Parents::from(vec![a.clone(), b.clone()]).iter().unwrap().collect(),
+			&[&a, &b]
+		);
+		assert_identical(
+			Parents::from(Object::from(vec![a.clone(), b.clone()])).iter().unwrap().collect(),
+			&[&a, &b]
+		);
+	}
 
+	#[test]
+	fn into_iter() {
+		let (a, b) = (Object::from(1), Object::from(2));
+
+		assert_eq!(Parents::default().into_iter().count(), 0);
+		assert_identical(
+			Parents::from(vec![a.clone(), b.clone()]).into_iter().collect(),
+			&[&a, &b]
+		);
+		assert_identical(
+			Parents::from(Object::from(vec![a.clone(), b.clone()])).into_iter().collect(),
+			&[&a, &b]
+		);
+
+		let mut count = 0;
+		for parent in Parents::from(vec![a.clone(), b.clone(), a.clone()]) {
+			assert!(parent.is_identical(&a) || parent.is_identical(&b));
+			count += 1;
+		}
+		assert_eq!(count, 3);
+	}
 
+	#[test]
+	fn remove_parent_none() {
+		let mut parents = Parents::default();
+		assert!(!parents.remove_parent(&Object::from(1)).unwrap());
+		assert_parents(&parents, &[]);
+	}
 
+	#[test]
+	fn remove_parent_builtin() {
+		let (a, b, c, d) = (Object::from(1), Object::from(2), Object::from(3), Object::from(4));
+		let mut parents = Parents::from(vec![a.clone(), b.clone(), c.clone(), d.clone()]);
 
+		// from the middle
+		assert!(parents.remove_parent(&b).unwrap());
+		assert_parents(&parents, &[&a, &c, &d]);
 
+		// from the end
+		assert!(parents.remove_parent(&d).unwrap());
+		assert_parents(&parents, &[&a, &c]);
 
+		// parents are compared by identity, not value.
+		assert!(!parents.remove_parent(&Object::from(1)).unwrap());
+		assert!(!parents.remove_parent(&d).unwrap());
+		assert_parents(&parents, &[&a, &c]);
+	}
 
+	#[test]
+	fn remove_parent_object() {
+		let (a, b, c) = (Object::from(1), Object::from(2), Object::from(3));
+		let list = Object::from(vec![a.clone(), b.clone(), c.clone(), b.clone()]);
+		let mut parents = Parents::from(list.clone());
 
+		// only the first one is removed
+		assert!(parents.remove_parent(&b).unwrap());
+		assert_parents(&parents, &[&a, &c, &b]);
 
+		assert!(parents.remove_parent(&b).unwrap());
+		assert_parents(&parents, &[&a, &c]);
 
+		assert!(!parents.remove_parent(&Object::from(3)).unwrap());
+		assert_eq!(list.downcast_ref::<crate::types::List>().unwrap().len(), 2);
+	}
+}
 
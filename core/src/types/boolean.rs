@@ -188,6 +188,12 @@ impl Boolean {
 		Ok(Text::from(*self))
 	}
 
+	/// Convert this to JSON, either `true` or `false`.
+	#[inline]
+	pub fn qs_to_json(&self, _: Args) -> Result<Text, !> {
+		Ok(Text::from(*self))
+	}
+
 	/// Convert this into a [`Boolean`].
 	///
 	/// This is simply a wrapper around [`Boolean::clone`](#method.clone).
@@ -199,7 +205,7 @@ impl Boolean {
 	/// See if a this is equal to the first argument.
 	///
 	/// Unlike most methods, the first argument is not implicitly converted to a  [`Boolean`] first.
-	pub fn qs_eql(&self, args: Args) -> Result<bool, crate::error::KeyError> {
+	pub fn qs_eql(&self, args: Args) -> Result<bool, crate::error::TypeError> {
 		match args.arg(0)?.downcast_ref::<Boolean>() {
 			Some(val) if *self == *val => Ok(true),
 			_ => Ok(false)
@@ -281,10 +287,57 @@ impl Boolean {
 		Ok(this.clone())
 	}
 
-	/// The hash for this.
+	/// Logical NAND of this and the first argument.
+	///
+	/// The first argument is converted to a [`Boolean`] if it isn't already.
+	pub fn qs_nand(&self, args: Args) -> crate::Result<Boolean> {
+		let rhs = args.arg(0)?.downcast_call::<Boolean>()?;
+
+		Ok(!(*self & rhs))
+	}
+
+	/// Logical NOR of this and the first argument.
+	///
+	/// The first argument is converted to a [`Boolean`] if it isn't already.
+	pub fn qs_nor(&self, args: Args) -> crate::Result<Boolean> {
+		let rhs = args.arg(0)?.downcast_call::<Boolean>()?;
+
+		Ok(!(*self | rhs))
+	}
+
+	/// Logical implication of this and the first argument (i.e. `!this | rhs`).
+	///
+	/// The first argument is converted to a [`Boolean`] if it isn't already.
+	pub fn qs_implies(&self, args: Args) -> crate::Result<Boolean> {
+		let rhs = args.arg(0)?.downcast_call::<Boolean>()?;
+
+		Ok(!*self | rhs)
+	}
+
+	/// Returns the first argument if this is true, and [`Null`](crate::types::Null) otherwise.
+	///
+	/// The argument is returned as-is, not called; use `if` for lazily evaluated branches. If no
+	/// argument is given, [`Null`](crate::types::Null) is returned.
+	pub fn qs_then(&self, args: Args) -> Result<Object, !> {
+		if self.0 {
+			Ok(args.arg(0).cloned().unwrap_or_default())
+		} else {
+			Ok(Object::default())
+		}
+	}
+
+	/// Returns the first argument if this is true, and the second one otherwise.
+	///
+	/// Like `then`, neither argument is called. If the chosen argument is missing,
+	/// [`Null`](crate::types::Null) is returned.
+	pub fn qs_then_else(&self, args: Args) -> Result<Object, !> {
+		Ok(args.arg(if self.0 { 0 } else { 1 }).cloned().unwrap_or_default())
+	}
+
+	/// The hash for this; see [`stable_hash`](crate::utils::stable_hash).
 	#[inline]
-	pub fn qs_hash(&self, _args: Args) -> crate::Result<Object> {
-		todo!("hash for Boolean")
+	pub fn qs_hash(&self, _: Args) -> Result<Number, !> {
+		Ok(crate::utils::stable_hash(self))
 	}
 }
 
@@ -308,9 +361,10 @@ for Boolean {
 		}
 	}
 }
-[(parents super::Basic) (convert "@bool")]:
+[(init_parent super::Basic super::Comparable) (parents super::Basic) (convert "@bool")]:
 	"@text" => method Boolean::qs_at_text,
 	"__inspect__" => method Boolean::qs___inspect__,
+	"to_json" => method Boolean::qs_to_json,
 	"@num"  => method Boolean::qs_at_num,
 	"@bool" => function Boolean::qs_at_bool,
 	"=="    => method Boolean::qs_eql,
@@ -321,6 +375,11 @@ for Boolean {
 	"|="    => function Boolean::qs_bitor_assign,
 	"^"     => method Boolean::qs_bitxor,
 	"^="    => function Boolean::qs_bitxor_assign,
+	"nand"  => method Boolean::qs_nand,
+	"nor"   => method Boolean::qs_nor,
+	"implies" => method Boolean::qs_implies,
+	"then"  => method Boolean::qs_then,
+	"then_else" => method Boolean::qs_then_else,
 	"<=>"   => method Boolean::qs_cmp,
 	"hash"  => method Boolean::qs_hash,
 }
@@ -329,6 +388,12 @@ for Boolean {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn to_json() {
+		assert_eq!(Boolean::TRUE.qs_to_json(args!()).unwrap().as_ref(), "true");
+		assert_eq!(Boolean::FALSE.qs_to_json(args!()).unwrap().as_ref(), "false");
+	}
+
 
 	#[test]
 	fn at_num() {
@@ -397,15 +462,82 @@ mod tests {
 	}
 
 	#[test]
-	#[ignore]
-	fn bitxor_assign() { todo!() }
+	fn bitxor_assign() {
+		macro_rules! assert_bitxor_assign {
+			($lhs:literal, $rhs:literal, $expected:expr) => {{
+				let obj = Object::from($lhs);
+				assert!(Boolean::qs_bitxor_assign(&obj, args!($rhs)).unwrap().is_identical(&obj));
+				assert_eq!(*obj.downcast_ref::<Boolean>().unwrap(), $expected);
+			}};
+		}
+
+		assert_bitxor_assign!(true, true, Boolean::FALSE);
+		assert_bitxor_assign!(true, false, Boolean::TRUE);
+		assert_bitxor_assign!(false, true, Boolean::TRUE);
+		assert_bitxor_assign!(false, false, Boolean::FALSE);
+
+		// `x ^ x` is always false, even when both sides are the same object.
+		let obj = Object::from(true);
+		Boolean::qs_bitxor_assign(&obj, args!(obj.clone())).unwrap();
+		assert_eq!(*obj.downcast_ref::<Boolean>().unwrap(), Boolean::FALSE);
+	}
+
+
+	#[test]
+	fn nand() {
+		assert_eq!(Boolean::TRUE.qs_nand(args!(true)).unwrap(), Boolean::FALSE);
+		assert_eq!(Boolean::TRUE.qs_nand(args!(false)).unwrap(), Boolean::TRUE);
+		assert_eq!(Boolean::FALSE.qs_nand(args!(true)).unwrap(), Boolean::TRUE);
+		assert_eq!(Boolean::FALSE.qs_nand(args!(false)).unwrap(), Boolean::TRUE);
+	}
+
+	#[test]
+	fn nor() {
+		assert_eq!(Boolean::TRUE.qs_nor(args!(true)).unwrap(), Boolean::FALSE);
+		assert_eq!(Boolean::TRUE.qs_nor(args!(false)).unwrap(), Boolean::FALSE);
+		assert_eq!(Boolean::FALSE.qs_nor(args!(true)).unwrap(), Boolean::FALSE);
+		assert_eq!(Boolean::FALSE.qs_nor(args!(false)).unwrap(), Boolean::TRUE);
+	}
+
+	#[test]
+	fn implies() {
+		assert_eq!(Boolean::TRUE.qs_implies(args!(true)).unwrap(), Boolean::TRUE);
+		assert_eq!(Boolean::TRUE.qs_implies(args!(false)).unwrap(), Boolean::FALSE);
+		assert_eq!(Boolean::FALSE.qs_implies(args!(true)).unwrap(), Boolean::TRUE);
+		assert_eq!(Boolean::FALSE.qs_implies(args!(false)).unwrap(), Boolean::TRUE);
+	}
+
+	#[test]
+	fn then() {
+		use crate::types::Null;
 
+		assert_eq!(*Boolean::TRUE.qs_then(args!(Number::ONE)).unwrap()
+			.downcast_ref::<Number>().unwrap(), Number::ONE);
+		assert!(Boolean::FALSE.qs_then(args!(Number::ONE)).unwrap().is_a::<Null>());
+		assert!(Boolean::TRUE.qs_then(args!()).unwrap().is_a::<Null>());
+		assert!(Boolean::FALSE.qs_then(args!()).unwrap().is_a::<Null>());
+	}
+
+	#[test]
+	fn then_else() {
+		use crate::types::Null;
+
+		assert_eq!(*Boolean::TRUE.qs_then_else(args!(Number::ONE, Number::ZERO)).unwrap()
+			.downcast_ref::<Number>().unwrap(), Number::ONE);
+		assert_eq!(*Boolean::FALSE.qs_then_else(args!(Number::ONE, Number::ZERO)).unwrap()
+			.downcast_ref::<Number>().unwrap(), Number::ZERO);
+		assert!(Boolean::FALSE.qs_then_else(args!(Number::ONE)).unwrap().is_a::<Null>());
+		assert!(Boolean::TRUE.qs_then_else(args!()).unwrap().is_a::<Null>());
+	}
 
 	#[test]
 	#[ignore]
 	fn cmp() { todo!(); }
 
 	#[test]
-	#[ignore]
-	fn hash() { todo!(); }
+	fn hash() {
+		assert_ne!(Boolean::TRUE.qs_hash(args!()).unwrap(), Boolean::FALSE.qs_hash(args!()).unwrap());
+		assert_eq!(Boolean::TRUE.qs_hash(args!()).unwrap(), Boolean::TRUE.qs_hash(args!()).unwrap());
+		assert_eq!(Boolean::FALSE.qs_hash(args!()).unwrap(), Boolean::FALSE.qs_hash(args!()).unwrap());
+	}
 }
\ No newline at end of file
@@ -70,10 +70,15 @@ impl BoundFunction {
 
 	pub fn call(this: &Object, args: Args) -> Result<Object> {
 		let bound_owner = this.get_attr_lit("__bound_object_owner__")?;
-		let mut args = crate::ArgsOld::from(args);
-		args.add_this(bound_owner);
+		let mut positional = vec![&bound_owner];
+		for arg in args.iter() {
+			positional.push(arg);
+		}
+
+		let owner_args = args.kwargs()
+			.fold(Args::new(positional), |owner_args, (name, value)| owner_args.with_kwarg(name, value));
 
-		this.get_attr_lit("__bound_object__")?.call_attr_old_old("()", args)
+		this.get_attr_lit("__bound_object__")?.call_attr_lit("()", owner_args)
 	}
 }
 
@@ -1,12 +1,19 @@
 use crate::{Object, Args, Result};
-use crate::types::Number;
+use crate::types::{Number, Boolean};
+use crate::error::ValueError;
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Comparable;
 
-fn compare(lhs: &Object, rhs: &Object) -> Result<Ordering> {
-	let num = lhs.call_attr_lit("<=>", &[rhs])?.downcast_call::<Number>()?;
+/// Compares two objects via `lhs`'s `<=>` attribute.
+pub(crate) fn compare(lhs: &Object, rhs: &Object) -> Result<Ordering> {
+	to_ordering(&lhs.call_attr_lit("<=>", &[rhs])?)
+}
+
+/// Converts the result of a `<=>`-style comparison into an [`Ordering`] based on its sign.
+pub(crate) fn to_ordering(cmp: &Object) -> Result<Ordering> {
+	let num = cmp.downcast_call::<Number>()?;
 	if num < Number::ZERO {
 		Ok(Ordering::Less)
 	} else if num > Number::ZERO {
@@ -32,6 +39,72 @@ impl Comparable {
 	pub fn qs_geq(this: &Object, args: Args) -> Result<bool> {
 		Ok(compare(this, args.arg(0)?)? != Ordering::Less)
 	}
+
+	/// Get the smaller of `this` and the first argument, preferring `this` if they're equal.
+	pub fn qs_min(this: &Object, args: Args) -> Result<Object> {
+		let rhs = args.arg(0)?;
+
+		if compare(rhs, this)? == Ordering::Less {
+			Ok(rhs.clone())
+		} else {
+			Ok(this.clone())
+		}
+	}
+
+	/// Get the larger of `this` and the first argument, preferring `this` if they're equal.
+	pub fn qs_max(this: &Object, args: Args) -> Result<Object> {
+		let rhs = args.arg(0)?;
+
+		if compare(rhs, this)? == Ordering::Greater {
+			Ok(rhs.clone())
+		} else {
+			Ok(this.clone())
+		}
+	}
+
+	/// Restrict `this` to be between the first and second arguments, inclusive.
+	///
+	/// It's an error for the lower bound to be greater than the upper bound.
+	pub fn qs_clamp(this: &Object, args: Args) -> Result<Object> {
+		let low = args.arg(0)?;
+		let high = args.arg(1)?;
+
+		if compare(low, high)? == Ordering::Greater {
+			return Err(ValueError::Messaged(
+				format!("clamp's lower bound {:?} is greater than its upper bound {:?}", low, high)
+			).into());
+		}
+
+		if compare(this, low)? == Ordering::Less {
+			Ok(low.clone())
+		} else if compare(this, high)? == Ordering::Greater {
+			Ok(high.clone())
+		} else {
+			Ok(this.clone())
+		}
+	}
+
+	/// Checks whether `low <= this <= high`, or `low <= this < high` if the third argument is
+	/// truthy.
+	pub fn qs_between(this: &Object, args: Args) -> Result<bool> {
+		let low = args.arg(0)?;
+		let high = args.arg(1)?;
+		let exclusive = args.arg(2)
+			.ok()
+			.map(|x| x.downcast_call::<Boolean>())
+			.transpose()?
+			.is_some_and(bool::from);
+
+		if compare(this, low)? == Ordering::Less {
+			return Ok(false);
+		}
+
+		match compare(this, high)? {
+			Ordering::Less => Ok(true),
+			Ordering::Equal => Ok(!exclusive),
+			Ordering::Greater => Ok(false)
+		}
+	}
 }
 
 
@@ -41,6 +114,10 @@ for Comparable [(parents super::Basic)]:
 	">" => function Comparable::qs_gth,
 	"<=" => function Comparable::qs_leq,
 	">=" => function Comparable::qs_geq,
+	"min" => function Comparable::qs_min,
+	"max" => function Comparable::qs_max,
+	"clamp" => function Comparable::qs_clamp,
+	"between" => function Comparable::qs_between,
 	// "==" => impls::eql,
 	// "!=" => impls::neq,
 }
@@ -58,53 +135,104 @@ impl From<Ordering> for crate::Object {
 #[allow(unused)]
 #[cfg(test)]
 mod tests {
-	// use crate::Object;
-	// dummy_object!(struct DummyCmp(f32); {
-	// 	"<=>" => function (|this, | {
-	// 		let this = args.this()?.try_downcast_ref::<DummyCmp>()?;
-	// 		let other = args.arg(0)?.try_downcast_ref::<DummyCmp>()?;
-
-	// 		Ok(this.0.partial_cmp(&other.0)
-	// 			.map(Into::into)
-	// 			.unwrap_or_default())
-	// 	})
-	// });
+	use super::*;
+	use crate::types::{Text, RustFn, ObjectType};
+
+	fn num(obj: Object) -> Number {
+		obj.downcast_call::<Number>().unwrap()
+	}
+
+	#[test]
+	fn min_max_clamp_between() {
+		let three = Object::from(3);
+
+		assert_eq!(num(Comparable::qs_min(&three, args!(5)).unwrap()), Number::from(3));
+		assert_eq!(num(Comparable::qs_min(&three, args!(-1.5)).unwrap()), Number::from(-1.5));
+		assert_eq!(num(Comparable::qs_max(&three, args!(5)).unwrap()), Number::from(5));
+		assert_eq!(num(Comparable::qs_max(&three, args!(-1.5)).unwrap()), Number::from(3));
+
+		assert_eq!(num(Comparable::qs_clamp(&three, args!(4, 10)).unwrap()), Number::from(4));
+		assert_eq!(num(Comparable::qs_clamp(&three, args!(0, 2)).unwrap()), Number::from(2));
+		assert_eq!(num(Comparable::qs_clamp(&three, args!(0, 10)).unwrap()), Number::from(3));
+		assert!(Comparable::qs_clamp(&three, args!(10, 0)).is_err());
+		assert!(Comparable::qs_clamp(&Number::NAN.into(), args!(1, 2)).is_err());
+
+		assert!(Comparable::qs_between(&three, args!(1, 3)).unwrap());
+		assert!(!Comparable::qs_between(&three, args!(1, 3, true)).unwrap());
+		assert!(Comparable::qs_between(&three, args!(3, 4, true)).unwrap());
+		assert!(!Comparable::qs_between(&three, args!(4, 5)).unwrap());
+		assert!(Comparable::qs_between(&three, args!(1)).is_err());
+
+		// numbers inherit the ones they don't override
+		assert_eq!(num(three.call_attr_lit("clamp", &[&0.into(), &2.into()]).unwrap()), Number::from(2));
+		assert_eq!(num(three.call_attr_lit("max", &[&7.into()]).unwrap()), Number::from(7));
+	}
+
+	#[test]
+	fn other_comparables_inherit() {
+		// a text that's comparable only by its length
+		let by_len = |text: &'static str| {
+			let obj = Object::new_with_parent(Text::new_static(text), vec![Comparable::mapping()]);
+			obj.set_attr_lit("<=>", Object::from(RustFn::new_boxed("<=>", |this, args| {
+				let len = |obj: &Object| obj.downcast_ref::<Text>().map(|text| text.len());
+				Ok(len(this).cmp(&len(args.arg(0)?)).into())
+			})));
+			obj
+		};
+
+		let text = |obj: Object| obj.downcast_ref::<Text>().unwrap().to_string();
+		let (short, mid, long) = (by_len("a"), by_len("bb"), by_len("ccc"));
+
+		assert_eq!(text(mid.call_attr_lit("min", &[&short]).unwrap()), "a");
+		assert_eq!(text(mid.call_attr_lit("max", &[&short]).unwrap()), "bb");
+		assert_eq!(text(long.call_attr_lit("clamp", &[&short, &mid]).unwrap()), "bb");
+		assert!(mid.call_attr_lit("between", &[&short, &long]).unwrap()
+			.downcast_call::<Boolean>().unwrap().into_inner());
+	}
+	/// Call `op` on `lhs` with `rhs`, returning the resulting boolean.
+	fn cmp_op(lhs: impl Into<Object>, op: &'static str, rhs: impl Into<Object>) -> bool {
+		lhs.into().call_attr_lit(op, &[&rhs.into()]).unwrap()
+			.downcast_call::<Boolean>().unwrap()
+			.into_inner()
+	}
 
 	#[test]
-	#[ignore]
 	fn lth() {
-		// let _obj = Object::from(DummyCmp(12.0));
-		// assert_eq!(, );
-		// Ok((cmp(args)? == Ordering::Less).into())
-		unimplemented!()
+		assert!(cmp_op(1, "<", 2));
+		assert!(!cmp_op(2, "<", 2));
+		assert!(!cmp_op(3, "<", 2.5));
+		assert!(cmp_op(false, "<", true));
+		assert!(!cmp_op(true, "<", true));
+		assert!(!cmp_op(true, "<", false));
 	}
 
 	#[test]
-	#[ignore]
 	fn gth() {
-		// Ok((cmp(args)? == Ordering::Greater).into())
-		unimplemented!()
+		assert!(!cmp_op(1, ">", 2));
+		assert!(!cmp_op(2, ">", 2));
+		assert!(cmp_op(3, ">", 2.5));
+		assert!(!cmp_op(false, ">", true));
+		assert!(!cmp_op(false, ">", false));
+		assert!(cmp_op(true, ">", false));
 	}
 
 	#[test]
-	#[ignore]
 	fn leq() {
-		// Ok((cmp(args)? != Ordering::Greater).into())
-		unimplemented!()
+		assert!(cmp_op(1, "<=", 2));
+		assert!(cmp_op(2, "<=", 2));
+		assert!(!cmp_op(3, "<=", 2.5));
+		assert!(cmp_op(false, "<=", true));
+		assert!(cmp_op(true, "<=", true));
+		assert!(!cmp_op(true, "<=", false));
 	}
 
 	#[test]
-	#[ignore]
 	fn geq() {
-		// Ok((cmp(args)? != Ordering::Less).into())
-		unimplemented!()
+		assert!(!cmp_op(1, ">=", 2));
+		assert!(cmp_op(2, ">=", 2));
+		assert!(cmp_op(3, ">=", 2.5));
+		assert!(!cmp_op(false, ">=", true));
+		assert!(cmp_op(false, ">=", false));
+		assert!(cmp_op(true, ">=", false));
 	}
 }
-
-
-
-
-
-
-
-
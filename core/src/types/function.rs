@@ -3,13 +3,53 @@ use crate::{Object, Result, Args};
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Function;
 
+/// Create a function that calls `first` and then passes its result to `second`.
+fn compose(first: Object, second: Object) -> Object {
+	Object::from(crate::types::RustFn::new_boxed("<composed>", move |this, args| {
+		let first_args = std::iter::once(this)
+			.chain(args.as_ref().iter().copied())
+			.collect::<Vec<_>>();
+
+		let intermediate = first.call_attr_lit("()", first_args)?;
+		second.call_attr_lit("()", &[&intermediate])
+	}))
+}
+
 impl Function {
-	pub fn qs_lsh(_this: &Object, _args: Args) -> Result<Object> {
-		todo!("<<")
+	/// Compose two functions, with the argument being called first.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to call first; its result is passed to `this`.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $add_one = { _1 + 1 };
+	/// $double = { _1 * 2 };
+	/// assert( (double << add_one)(3) == 8 );
+	/// ```
+	pub fn qs_lsh(this: &Object, args: Args) -> Result<Object> {
+		let first = args.arg(0)?;
+
+		Ok(compose(first.clone(), this.clone()))
 	}
 
-	pub fn qs_rsh(_this: &Object, _args: Args) -> Result<Object> {
-		todo!(">>")
+	/// Compose two functions, with `this` being called first.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to pass the result of `this` to.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $add_one = { _1 + 1 };
+	/// $double = { _1 * 2 };
+	/// assert( (add_one >> double)(3) == 8 );
+	/// ```
+	pub fn qs_rsh(this: &Object, args: Args) -> Result<Object> {
+		let second = args.arg(0)?;
+
+		Ok(compose(this.clone(), second.clone()))
 	}
 
 	pub fn qs_curry(_this: &Object, _args: Args) -> Result<Object> {
@@ -24,14 +64,50 @@ for Function [(parents super::Basic)]:
 	"curry" => function Function::qs_curry
 }
 
+#[cfg(test)]
 mod tests {
+	use super::*;
+	use crate::types::{RustFn, Number};
+
+	fn add_one() -> Object {
+		Object::from(RustFn::new("add_one", |this, _| this.call_attr_lit("+", &[&1.into()])))
+	}
+
+	fn double() -> Object {
+		Object::from(RustFn::new("double", |this, _| this.call_attr_lit("*", &[&2.into()])))
+	}
+
+	fn call(func: &Object, arg: i32) -> Number {
+		*func.call_attr_lit("()", &[&arg.into()]).unwrap().downcast_ref::<Number>().unwrap()
+	}
+
 	#[test]
-	#[ignore]
-	fn lsh() { todo!() }
+	fn lsh() {
+		let composed = Function::qs_lsh(&add_one(), args!(double())).unwrap();
+		assert_eq!(call(&composed, 3), Number::from(7));
+
+		let composed = Function::qs_lsh(&double(), args!(add_one())).unwrap();
+		assert_eq!(call(&composed, 3), Number::from(8));
+	}
 
 	#[test]
-	#[ignore]
-	fn rsh() { todo!() }
+	fn rsh() {
+		let composed = Function::qs_rsh(&add_one(), args!(double())).unwrap();
+		assert_eq!(call(&composed, 3), Number::from(8));
+
+		let composed = Function::qs_rsh(&double(), args!(add_one())).unwrap();
+		assert_eq!(call(&composed, 3), Number::from(7));
+	}
+
+	#[test]
+	fn compose_via_attrs() {
+		let composed = add_one().call_attr_lit(">>", &[&double()]).unwrap();
+		assert_eq!(call(&composed, 5), Number::from(12));
+
+		// the composed function can itself be composed
+		let composed = composed.call_attr_lit("<<", &[&add_one()]).unwrap();
+		assert_eq!(call(&composed, 5), Number::from(14));
+	}
 
 	#[test]
 	#[ignore]
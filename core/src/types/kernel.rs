@@ -1,36 +1,110 @@
 use crate::{Args, Object, Error, Result};
-use crate::types::{Boolean, Text, Number};
+use crate::types::{Boolean, Text, Number, RustFn, BoundFunction, Function, ObjectType};
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::sync::RwLock;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Kernel;
 
+thread_local!(
+	static OUTPUT: RefCell<Box<dyn Write>> = RefCell::new(Box::new(io::stdout()));
+);
+
+/// A function that parses and executes Quest source code.
+///
+/// The core doesn't know how to parse source code itself, so one of these has to be supplied via
+/// [`Kernel::set_evaluator`] for [`Kernel::eval`] to work.
+pub type Evaluator = fn(&str) -> Result<Object>;
+
+lazy_static::lazy_static! {
+	static ref EVALUATOR: RwLock<Option<Evaluator>> = RwLock::new(None);
+}
+
 fn display(args: &[&Object], newline: bool) -> Result<()> {
-	print!("{}",
-		args.iter()
-			.map(|x| x.downcast_call::<Text>().map(|x| x.to_string()))
-			.collect::<Result<Vec<_>>>()?
-			.join(" ")
-	);
-
-	if newline {
-		println!();
-		Ok(())
-	} else {
-		use std::io::{self, Write};
+	let text = args.iter()
+		.map(|x| x.downcast_call::<Text>().map(|x| x.to_string()))
+		.collect::<Result<Vec<_>>>()?
+		.join(" ");
+
+	OUTPUT.with(|output| {
+		let mut output = output.borrow_mut();
 
-		io::stdout()
-			.flush()
-			.map_err(|err| Error::Messaged(format!("couldn't flush: {}", err)))
+		if newline {
+			writeln!(output, "{}", text)
+		} else {
+			write!(output, "{}", text)
+		}.and_then(|_| output.flush())
+	}).map_err(|err| Error::Messaged(format!("couldn't write output: {}", err)))
+}
+
+/// Call `obj` if it's a function (such as a block or a [`RustFn`]), otherwise just return it.
+///
+/// Only functions are called: other values with a `"()"` attribute (such as numbers, where it's
+/// multiplication) are returned as-is.
+fn call_or_value(obj: &Object) -> Result<Object> {
+	let rustfn = obj.downcast_ref::<RustFn>().map(|rustfn| rustfn.clone());
+
+	if let Some(rustfn) = rustfn {
+		rustfn.call(obj, Args::default())
+	} else if obj.is_a::<BoundFunction>() || obj.is_descendant_of(&Function::mapping())? {
+		obj.call_attr_lit("()", &[])
+	} else {
+		Ok(obj.clone())
 	}
 }
 
 impl Kernel {
+	/// Replace where the current thread's `print`, `puts`, and friends write to, returning the
+	/// old writer. By default, this is stdout.
+	pub fn set_output(output: Box<dyn Write>) -> Box<dyn Write> {
+		OUTPUT.with(|old| old.replace(output))
+	}
+
+	/// Set the function [`eval`](#method.eval) uses to run source code, returning the old one.
+	pub fn set_evaluator(evaluator: Evaluator) -> Option<Evaluator> {
+		EVALUATOR.write().expect("evaluator poisoned").replace(evaluator)
+	}
+
+	/// Parse and execute `src` within the current [`Binding`](crate::Binding), so it has access to
+	/// the current stackframe's variables.
+	///
+	/// An error is returned if no evaluator has been set.
+	pub fn eval(src: &str) -> Result<Object> {
+		let evaluator = *EVALUATOR.read().expect("evaluator poisoned");
+
+		match evaluator {
+			Some(evaluator) => evaluator(src),
+			None => Err(Error::Messaged("no evaluator has been set".into()))
+		}
+	}
+
+	/// `eval(src)`: Run the text `src` as Quest code in the current scope, returning its result.
+	pub fn qs_eval(_: &Object, args: Args) -> Result<Object> {
+		let src = args.arg_as::<Text>(0, "eval")?;
+
+		Kernel::eval(src.as_ref())
+	}
+
+	/// `parse_json(text)`: Parse `text` as JSON; see [`json::parse`](crate::json::parse).
+	pub fn qs_parse_json(_: &Object, args: Args) -> Result<Object> {
+		let src = args.arg_as::<Text>(0, "parse_json")?;
+
+		crate::json::parse(src.as_ref())
+	}
+
+	/// `if(cond, then, else?)`: call `then` if `cond` is truthy, otherwise `else`.
+	///
+	/// Each of `cond`, `then`, and `else` can either be a function (a block, a `RustFn`, or a
+	/// bound function) or a plain value. Functions are called and their result used; anything
+	/// else, including a `Text` or `Number` with its own `"()"`, is used as-is. If `else` is
+	/// omitted and `cond` is falsey, `null` is returned.
 	pub fn qs_if(_: &Object, args: Args) -> Result<Object> {
-		if args.arg(0)?.downcast_call::<Boolean>()?.into() {
-			args.arg(1)?.clone()
+		if call_or_value(args.arg(0)?)?.downcast_call::<Boolean>()?.into() {
+			call_or_value(args.arg(1)?)
 		} else {
-			args.arg(2).map(Clone::clone).unwrap_or_default()
-		}.call_attr_lit("()", &[])
+			args.arg(2).map(call_or_value).unwrap_or_else(|_| Ok(Object::default()))
+		}
 	}
 
 	pub fn qs_disp(_: &Object, args: Args) -> Result<Object> {
@@ -41,6 +115,16 @@ impl Kernel {
 		display(args.as_ref(), false).map(|_| Object::default())
 	}
 
+	/// Write each argument's `@text`, separated by spaces.
+	pub fn qs_print(_: &Object, args: Args) -> Result<Object> {
+		display(args.as_ref(), false).map(|_| Object::default())
+	}
+
+	/// Like [`qs_print`](#method.qs_print), but with a trailing newline.
+	pub fn qs_puts(_: &Object, args: Args) -> Result<Object> {
+		display(args.as_ref(), true).map(|_| Object::default())
+	}
+
 	pub fn qs_while(_: &Object, args: Args) -> Result<Object> {
 		let cond = args.arg(0)?;
 		let body = args.arg(1)?;
@@ -48,8 +132,8 @@ impl Kernel {
 		// 	b.set_attr_old("name", Object::from("while"))?;
 
 			let mut result = Object::default();
-			while cond.call_attr_lit("()", &[])?.downcast_call::<Boolean>()?.into() {
-				result = body.call_attr_lit("()", &[])?;
+			while call_or_value(cond)?.downcast_call::<Boolean>()?.into() {
+				result = call_or_value(body)?;
 			};
 			Ok(result)
 		// })
@@ -183,6 +267,7 @@ for Kernel [(parents super::Pristine)]: // todo: do i want its parent to be pris
 	"Function" => const super::Function::mapping(),
 	"Kernel" => const Kernel::mapping(),
 	"List" => const super::List::mapping(),
+	"Map" => const super::Map::mapping(),
 	"Null" => const super::Null::mapping(),
 	"Number" => const super::Number::mapping(),
 	"Pristine" => const super::Pristine::mapping(),
@@ -194,8 +279,13 @@ for Kernel [(parents super::Pristine)]: // todo: do i want its parent to be pris
 	"if" => function Kernel::qs_if, 
 	"disp" => function Kernel::qs_disp,
 	"dispn" => function Kernel::qs_dispn,
+	"print" => function Kernel::qs_print,
+	"puts" => function Kernel::qs_puts,
+	"print_line" => function Kernel::qs_puts,
 	"quit" => function Kernel::qs_quit,
 	"system" => function Kernel::qs_system,
+	"eval" => function Kernel::qs_eval,
+	"parse_json" => function Kernel::qs_parse_json,
 	"rand" => function Kernel::qs_rand,
 	"prompt" => function Kernel::qs_prompt,
 	"while" => function Kernel::qs_while,
@@ -264,18 +354,167 @@ mod tests {
 
 		assert_mapping_eq!(
 			"Basic" Basic, /*"Block" Block,*/ "Boolean" Boolean, "Function" Function,
-			"Kernel" Kernel, "List" List, "Null" Null, "Number" Number,
+			"Kernel" Kernel, "List" List, "Map" Map, "Null" Null, "Number" Number,
 			"Pristine" Pristine, "RustFn" RustFn, "Text" Text
 		);
 	}
 
-	#[test]
-	#[ignore]
-	fn r#if() { todo!() }
+	mod control_flow {
+		use super::super::*;
+		use crate::types::{Null, RustFn};
+		use std::sync::Arc;
+		use std::sync::atomic::{AtomicI64, Ordering};
+
+		fn callable<F>(func: F) -> Object
+		where
+			F: Fn() -> Result<Object> + Send + Sync + 'static
+		{
+			RustFn::new_boxed("callable", move |_, _| func()).into()
+		}
 
-	#[test]
-	#[ignore]
-	fn disp() { todo!() }
+		#[test]
+		fn if_true() {
+			let result = Kernel::qs_if(&Kernel.into(), args!(true, false, true)).unwrap();
+			assert_eq!(*result.downcast_ref::<Boolean>().unwrap(), Boolean::FALSE);
+
+			let then = callable(|| Ok(Text::from("then").into()));
+			let result = Kernel::qs_if(&Kernel.into(), args!(callable(|| Ok(true.into())), then));
+			assert_eq!(result.unwrap().downcast_call::<Text>().unwrap(), Text::from("then"));
+		}
+
+		#[test]
+		fn if_false() {
+			let result = Kernel::qs_if(&Kernel.into(), args!(false, false, true)).unwrap();
+			assert_eq!(*result.downcast_ref::<Boolean>().unwrap(), Boolean::TRUE);
+
+			let r#else = callable(|| Ok(Text::from("else").into()));
+			let result = Kernel::qs_if(&Kernel.into(), args!(false, true, r#else));
+			assert_eq!(result.unwrap().downcast_call::<Text>().unwrap(), Text::from("else"));
+		}
+
+		#[test]
+		fn if_without_else() {
+			let result = Kernel::qs_if(&Kernel.into(), args!(false, true)).unwrap();
+			assert!(result.is_a::<Null>());
+		}
+
+		#[test]
+		fn if_plain_values() {
+			let result = Kernel::qs_if(&Kernel.into(), args!(true, 3)).unwrap();
+			assert_eq!(result.downcast_call::<Number>().unwrap(), Number::from(3));
+
+			let yes = Kernel::qs_if(&Kernel.into(), args!(true, Text::from("yes"), Text::from("no")));
+			assert_eq!(yes.unwrap().downcast_call::<Text>().unwrap(), Text::from("yes"));
+
+			let no = Kernel::qs_if(&Kernel.into(), args!(false, Text::from("yes"), Text::from("no")));
+			assert_eq!(no.unwrap().downcast_call::<Text>().unwrap(), Text::from("no"));
+		}
+
+		#[test]
+		fn while_plain_values() {
+			let count = Arc::new(AtomicI64::new(3));
+			let cond = callable({
+				let count = count.clone();
+				move || Ok((count.fetch_sub(1, Ordering::SeqCst) > 0).into())
+			});
+
+			let result = Kernel::qs_while(&Kernel.into(), args!(cond, Text::from("body"))).unwrap();
+			assert_eq!(result.downcast_call::<Text>().unwrap(), Text::from("body"));
+
+			let result = Kernel::qs_while(&Kernel.into(), args!(false, 3)).unwrap();
+			assert!(result.is_a::<Null>());
+
+			let result = Kernel::qs_while(&Kernel.into(), args!(Text::from(""), 3)).unwrap();
+			assert!(result.is_a::<Null>());
+		}
+
+		#[test]
+		fn while_counts_down() {
+			let count = Arc::new(AtomicI64::new(5));
+			let iterations = Arc::new(AtomicI64::new(0));
+
+			let cond = callable({
+				let count = count.clone();
+				move || Ok((count.load(Ordering::SeqCst) > 0).into())
+			});
+			let body = callable({
+				let (count, iterations) = (count.clone(), iterations.clone());
+				move || {
+					iterations.fetch_add(1, Ordering::SeqCst);
+					Ok(count.fetch_sub(1, Ordering::SeqCst).into())
+				}
+			});
+
+			let result = Kernel::qs_while(&Kernel.into(), args!(cond, body)).unwrap();
+
+			assert_eq!(count.load(Ordering::SeqCst), 0);
+			assert_eq!(iterations.load(Ordering::SeqCst), 5);
+			assert_eq!(result.downcast_call::<Number>().unwrap(), Number::from(1));
+		}
+
+		#[test]
+		fn while_propagates_errors() {
+			let body = callable(|| Err(Error::Messaged("oops".into())));
+
+			let err = Kernel::qs_while(&Kernel.into(), args!(true, body)).unwrap_err();
+			assert_eq!(err, Error::Messaged("oops".into()));
+		}
+	}
+
+	mod output {
+		use super::super::*;
+		use std::rc::Rc;
+
+		#[derive(Clone, Default)]
+		struct Capture(Rc<RefCell<Vec<u8>>>);
+
+		impl Write for Capture {
+			fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+				self.0.borrow_mut().write(buf)
+			}
+
+			fn flush(&mut self) -> io::Result<()> {
+				Ok(())
+			}
+		}
+
+		fn capture(func: impl FnOnce() -> Result<Object>) -> String {
+			let capture = Capture::default();
+			let old = Kernel::set_output(Box::new(capture.clone()));
+			func().unwrap();
+			Kernel::set_output(old);
+
+			let out = capture.0.borrow();
+			String::from_utf8(out.clone()).unwrap()
+		}
+
+		#[test]
+		fn print() {
+			let out = capture(|| {
+				Kernel::qs_print(&Kernel.into(), args!(Number::from(12), Text::from("foo")))?;
+				Kernel::qs_print(&Kernel.into(), args!(Boolean::TRUE))
+			});
+
+			assert_eq!(out, "12 footrue");
+		}
+
+		#[test]
+		fn puts() {
+			let out = capture(|| {
+				Kernel::qs_puts(&Kernel.into(), args!(Text::from("a"), Number::from(1.5), Boolean::FALSE))?;
+				Kernel::qs_puts(&Kernel.into(), args!())
+			});
+
+			assert_eq!(out, "a 1.5 false\n\n");
+		}
+
+		#[test]
+		fn disp() {
+			let out = capture(|| Kernel::qs_disp(&Kernel.into(), args!(Text::from("x"))));
+
+			assert_eq!(out, "x\n");
+		}
+	}
 
 	#[test]
 	#[ignore]
@@ -290,16 +529,37 @@ mod tests {
 	fn rand() { todo!() }
 
 	#[test]
-	#[ignore]
-	fn eval() { todo!() }
+	fn parse_json() {
+		use super::*;
+		use crate::types::List;
+
+		let list = Kernel::qs_parse_json(&Kernel.into(), args!(Text::from("[1, {\"a\": null}]"))).unwrap();
+		assert_eq!(list.downcast_ref::<List>().unwrap().len(), 2);
+
+		assert!(Kernel::qs_parse_json(&Kernel.into(), args!(Text::from("[1,"))).is_err());
+	}
 
 	#[test]
-	#[ignore]
-	fn prompt() { todo!() }
+	fn eval() {
+		use super::*;
+
+		assert!(Kernel::qs_eval(&Kernel.into(), args!(Text::from("1"))).is_err());
+
+		fn parse_number(src: &str) -> Result<Object> {
+			Number::from_str_radix(src, 10).map(Object::from).map_err(|err| err.to_string().into())
+		}
+
+		Kernel::set_evaluator(parse_number);
+
+		let result = Kernel::qs_eval(&Kernel.into(), args!(Text::from("12"))).unwrap();
+		assert_eq!(*result.downcast_ref::<Number>().unwrap(), Number::from(12));
+		assert!(Kernel::qs_eval(&Kernel.into(), args!(Text::from("x"))).is_err());
+		assert!(Kernel::qs_eval(&Kernel.into(), args!()).is_err());
+	}
 
 	#[test]
 	#[ignore]
-	fn r#while() { todo!() }
+	fn prompt() { todo!() }
 
 	#[test]
 	#[ignore]
@@ -1,7 +1,10 @@
 use crate::{Object, Args};
+use crate::error::{ValueError, KeyError};
 use crate::literals::__INSPECT__;
-use crate::types::{Text, Boolean, Number};
+use crate::types::{Text, Boolean, Number, Map};
+use crate::types::comparable::{compare, to_ordering};
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::{self, Debug, Formatter};
 
@@ -92,9 +95,32 @@ impl List {
 		}
 	}
 
-	/// Sets a single element in a list
-	pub fn set(&self, _idx: isize, _ele: Object)  {
-		unimplemented!()
+	/// Sets a single element in a list.
+	///
+	/// If `idx` is past the end of the list, the list is padded with [`Null`](crate::types::Null)s
+	/// up to it. Negative indices count from the end, and must be within the list. Padding the list
+	/// past [`max_repeat_len`](crate::utils::max_repeat_len) elements is also out of bounds.
+	pub fn set(&mut self, idx: isize, ele: Object) -> Result<(), KeyError> {
+		let len = self.len();
+		let idx =
+			if idx.is_negative() {
+				correct_index(idx, len).ok_or(KeyError::OutOfBounds { idx, len })?
+			} else if len <= idx as usize && crate::utils::max_repeat_len() <= idx as usize {
+				return Err(KeyError::OutOfBounds { idx, len });
+			} else {
+				idx as usize
+			};
+
+		let list = self.0.to_mut();
+
+		if idx < len {
+			list[idx] = ele;
+		} else {
+			list.resize_with(idx, Object::default);
+			list.push(ele);
+		}
+
+		Ok(())
 	}
 
 	/// Sets a range of elements within the list.
@@ -130,6 +156,68 @@ impl List {
 
 		Ok(true)
 	}
+
+	/// Check to see if two lists are structurally equal, recursing into nested lists.
+	///
+	/// Elements that aren't lists are compared with their `==` attribute. Lists that (directly
+	/// or indirectly) contain themselves are handled: a pair of lists that's already being
+	/// compared further up is assumed to be equal, which lets the comparison terminate.
+	pub fn eql_deep(&self, rhs: &List) -> crate::Result<bool> {
+		self.eql_deep_with(rhs, &mut vec![])
+	}
+
+	fn eql_deep_with(&self, rhs: &List, seen: &mut Vec<(usize, usize)>) -> crate::Result<bool> {
+		if self.len() != rhs.len() {
+			return Ok(false);
+		}
+
+		for (lhs, rhs) in self.iter().zip(rhs.iter()) {
+			if !eql_deep_obj(lhs, rhs, seen)? {
+				return Ok(false)
+			}
+		}
+
+		Ok(true)
+	}
+
+	/// Create a new list with the elements of any nested lists spliced in, up to `depth` levels.
+	///
+	/// A `depth` of zero just returns a copy; `usize::MAX` flattens completely.
+	///
+	/// # Errors
+	/// A [`ValueError`] is returned if a nested list (directly or indirectly) contains itself, as
+	/// it could never be completely flattened.
+	pub fn flatten(&self, depth: usize) -> crate::Result<List> {
+		let mut flattened = Vec::with_capacity(self.len());
+		self.flatten_into(depth, &mut flattened, &mut vec![])?;
+
+		Ok(List::from(flattened))
+	}
+
+	fn flatten_into(&self, depth: usize, flattened: &mut Vec<Object>, seen: &mut Vec<usize>) -> crate::Result<()> {
+		for ele in self.iter() {
+			let list =
+				if depth == 0 {
+					None
+				} else {
+					ele.downcast_ref::<List>().map(|list| list.clone())
+				};
+
+			match list {
+				Some(_) if seen.contains(&ele.id()) =>
+					return Err(ValueError::Messaged("can't flatten a list that contains itself".into()).into()),
+				Some(list) => {
+					seen.push(ele.id());
+					list.flatten_into(depth - 1, flattened, seen)?;
+					seen.pop();
+				},
+				None => flattened.push(ele.clone())
+			}
+		}
+
+		Ok(())
+	}
+
 	/// Add a new element to the end of the list.
 	#[inline]
 	pub fn push(&mut self, what: Object) {
@@ -158,6 +246,18 @@ impl List {
 		}
 	}
 
+	/// Remove the element at `idx`, shifting everything after it down.
+	///
+	/// Returns `None` if `idx` is out of bounds.
+	#[inline]
+	pub fn remove(&mut self, idx: usize) -> Option<Object> {
+		if idx < self.len() {
+			Some(self.0.to_mut().remove(idx))
+		} else {
+			None
+		}
+	}
+
 	/// Find an element in the list
 	pub fn find(&self, needle: &Object) -> crate::Result<Option<usize>> {
 		for (idx, val) in self.iter().enumerate() {
@@ -167,6 +267,32 @@ impl List {
 		}
 		Ok(None)
 	}
+
+	/// Returns a sorted copy of the list, comparing elements with `cmp`.
+	///
+	/// The sort is stable. If `cmp` ever returns an error, sorting stops and the error is returned.
+	pub fn try_sorted_by<F>(&self, mut cmp: F) -> crate::Result<List>
+	where
+		F: FnMut(&Object, &Object) -> crate::Result<Ordering>
+	{
+		merge_sort(self.as_ref(), &mut cmp).map(List::from)
+	}
+
+	/// Returns a sorted copy of the list, comparing the keys `key` returns for each element.
+	///
+	/// `key` is only called once per element, and keys are compared with `<=>`. The sort is
+	/// stable, and any error from `key` or while comparing is returned.
+	pub fn try_sorted_by_key<F>(&self, key: F) -> crate::Result<List>
+	where
+		F: FnMut(&Object) -> crate::Result<Object>
+	{
+		let keys = self.iter().map(key).collect::<crate::Result<Vec<_>>>()?;
+		let pairs = keys.into_iter().zip(self.iter().cloned()).collect::<Vec<_>>();
+
+		let sorted = merge_sort(&pairs, &mut |(lhs, _), (rhs, _)| compare(lhs, rhs))?;
+
+		Ok(sorted.into_iter().map(|(_, obj)| obj).collect())
+	}
 }
 
 impl From<List> for Vec<Object> {
@@ -187,6 +313,12 @@ impl From<Vec<Object>> for Object {
 	}
 }
 
+impl std::iter::FromIterator<Object> for List {
+	fn from_iter<I: IntoIterator<Item=Object>>(iter: I) -> Self {
+		List::new(iter.into_iter().collect::<Vec<_>>())
+	}
+}
+
 impl AsRef<[Object]> for List {
 	fn as_ref(&self) -> &[Object] {
 		self.0.as_ref()
@@ -318,6 +450,72 @@ impl List {
 	}
 }
 
+fn eql_deep_obj(lhs: &Object, rhs: &Object, seen: &mut Vec<(usize, usize)>) -> crate::Result<bool> {
+	let lhs_list = lhs.downcast_ref::<List>().map(|list| list.clone());
+	let rhs_list = rhs.downcast_ref::<List>().map(|list| list.clone());
+
+	let (lhs_list, rhs_list) =
+		match (lhs_list, rhs_list) {
+			(Some(lhs_list), Some(rhs_list)) => (lhs_list, rhs_list),
+			_ => return lhs.eq_obj(rhs)
+		};
+
+	let pair = (lhs.id(), rhs.id());
+
+	if seen.contains(&pair) {
+		return Ok(true);
+	}
+
+	seen.push(pair);
+	let result = lhs_list.eql_deep_with(&rhs_list, seen);
+	seen.pop();
+
+	result
+}
+
+/// A stable merge sort that stops at the first error `cmp` returns.
+fn merge_sort<T: Clone, F>(items: &[T], cmp: &mut F) -> crate::Result<Vec<T>>
+where
+	F: FnMut(&T, &T) -> crate::Result<Ordering>
+{
+	if items.len() <= 1 {
+		return Ok(items.to_vec());
+	}
+
+	let (lhs, rhs) = items.split_at(items.len() / 2);
+	let lhs = merge_sort(lhs, cmp)?;
+	let rhs = merge_sort(rhs, cmp)?;
+
+	let mut merged = Vec::with_capacity(items.len());
+	let mut lhs = lhs.into_iter().peekable();
+	let mut rhs = rhs.into_iter().peekable();
+
+	while let (Some(l), Some(r)) = (lhs.peek(), rhs.peek()) {
+		if cmp(r, l)? == Ordering::Less {
+			merged.extend(rhs.next());
+		} else {
+			merged.extend(lhs.next());
+		}
+	}
+
+	merged.extend(lhs);
+	merged.extend(rhs);
+	Ok(merged)
+}
+
+/// Convert `size` to the size of a group of elements, which must be positive.
+fn group_size(size: Number, method: &str) -> Result<usize, ValueError> {
+	match usize::try_from(size.floor()) {
+		Ok(size) if size != 0 => Ok(size),
+		_ => Err(ValueError::Messaged(format!("{} size must be positive, not {}", method, size)))
+	}
+}
+
+/// Convert `count` to a number of elements, clamping it to `0..=len`.
+fn clamp_count(count: Number, len: usize) -> usize {
+	usize::try_from(count.floor()).map(|count| count.min(len)).unwrap_or(0)
+}
+
 fn correct_index(idx: isize, len: usize) -> Option<usize> {
 	if !idx.is_negative() {
 		if (idx as usize) < len {
@@ -337,6 +535,19 @@ fn correct_index(idx: isize, len: usize) -> Option<usize> {
 
 /// Quest methods
 impl List {
+	/// Creates a new list containing each argument, in order.
+	///
+	/// # Quest Examples
+	///
+	/// ```quest
+	/// assert(List::$new(1, "two", 3.0) == [1, "two", 3.0]);
+	/// assert(List::$new() == []);
+	/// ```
+	#[inline]
+	pub fn qs_new(_: &Object, args: Args) -> Result<List, !> {
+		Ok(args.into())
+	}
+
 	/// Simply returns the list.
 	///
 	/// # Quest Examples
@@ -365,7 +576,7 @@ impl List {
 	/// ```quest
 	/// $list = [1, "a", true];
 	///
-	/// assert(list.$@text() == '[1, "a", true]')
+	/// assert(list.$@text() == "[1, \"a\", true]")
 	/// ```
 	#[inline]
 	pub fn qs_at_text(&self, _: Args) -> crate::Result<Text> {
@@ -378,7 +589,7 @@ impl List {
 	/// ```quest
 	/// $list = [1, "a", true];
 	///
-	/// assert(list.$__inspect__() == '[1, "a", true]')
+	/// assert(list.$__inspect__() == "[1, \"a\", true]")
 	/// ```
 	#[inline]
 	#[allow(non_snake_case)]
@@ -400,6 +611,31 @@ impl List {
 		Ok(Boolean::from(self))
 	}
 
+	/// Convert this to a JSON array, converting each element with its `to_json` method.
+	///
+	/// # Errors
+	/// If any element can't be converted.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, "a", [null]].$to_json() == "[1,\"a\",[null]]");
+	/// ```
+	pub fn qs_to_json(&self, _: Args) -> crate::Result<Text> {
+		self.to_json().map(Text::from)
+	}
+
+	/// Converts this into a [`Number`], which is the list's length.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, "a", true].$@num() == 3);
+	/// assert([].$@num() + 1 == 1);
+	/// ```
+	#[inline]
+	pub fn qs_at_num(&self, _: Args) -> Result<Number, !> {
+		Ok(Number::from(self.len()))
+	}
+
 	/// Clones this object.
 	///
 	/// # Quest Examples
@@ -440,6 +676,69 @@ impl List {
 			.map(|x| x.map(Object::from).unwrap_or_default())
 	}
 
+	/// Checks to see if an element is within the list.
+	///
+	/// Elements are compared via their `==` attribute.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The element to look for.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = [1, "a", true];
+	///
+	/// assert(list.$contains("a"));
+	/// assert(!list.$contains("1"));
+	/// ```
+	#[inline]
+	pub fn qs_contains(&self, args: Args) -> crate::Result<bool> {
+		self.find(args.arg(0)?).map(|idx| idx.is_some())
+	}
+
+	/// Finds the index of an element within the list, optionally starting at an offset.
+	///
+	/// Elements are compared via their `==` attribute. If the element isn't found at or after the
+	/// offset, [`Null`](crate::types::Null) is returned.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The element to find.
+	/// 2. (optional, `@num`) The index to start searching from; negative indices count from the end.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = ["a", "b", "a"];
+	///
+	/// assert(list.$index_of("a") == 0);
+	/// assert(list.$index_of("a", 1) == 2);
+	/// assert(list.$index_of("c") == null);
+	/// ```
+	pub fn qs_index_of(&self, args: Args) -> crate::Result<Object> {
+		let needle = args.arg(0)?;
+
+		let start =
+			if let Ok(start) = args.arg(1) {
+				let start = start.downcast_call::<Number>()?.floor() as isize;
+
+				if let Some(start) = correct_index(start, self.len()) {
+					start
+				} else {
+					return Ok(Object::default())
+				}
+			} else {
+				0
+			};
+
+		for (idx, ele) in self.iter().enumerate().skip(start) {
+			if ele.eq_obj(needle)? {
+				return Ok(idx.into());
+			}
+		}
+
+		Ok(Object::default())
+	}
+
 	/// Remove all elements from the list and returns the list.
 	///
 	/// # Quest Examples
@@ -501,6 +800,52 @@ impl List {
 		}
 	}
 
+	/// Gets an element or range from the list.
+	///
+	/// This is the same as `get`.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = ['a', 2, 3, false];
+	///
+	/// assert(list[0] == 'a');
+	/// assert(list[-1] == false);
+	/// assert(list[1, 2] == [2, 3]);
+	/// assert(list[9] == null);
+	/// ```
+	#[inline]
+	pub fn qs_index(&self, args: Args) -> crate::Result<Object> {
+		self.qs_get(args)
+	}
+
+	/// Sets the element at an index, returning the list.
+	///
+	/// If the index is past the end of the list, the list is padded with `null`s up to it.
+	///
+	/// # Arguments
+	///
+	/// 1. (required, `@num`) The index to set.
+	/// 2. (required) The element to set it to.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = [1, 2];
+	///
+	/// list[-1] = 3;
+	/// assert(list == [1, 3]);
+	///
+	/// list[3] = 4;
+	/// assert(list == [1, 3, null, 4]);
+	/// ```
+	pub fn qs_index_assign(this: &Object, args: Args) -> crate::Result<Object> {
+		let idx = args.arg(0)?.downcast_call::<Number>()?.floor() as isize;
+		let ele = args.arg(1)?;
+
+		this.try_downcast_mut::<Self>()?.set(idx, ele.clone())?;
+
+		Ok(this.clone())
+	}
+
 	/// Sets an element or range of the list to an element or list.
 	///
 	/// This allows you to delete chunks of the list if you want to by setting them to empty lists.
@@ -558,6 +903,24 @@ impl List {
 		}
 	}
 
+	/// Check to see if two lists are equal, recursing into any nested lists.
+	///
+	/// Unlike `==`, nested lists are compared element-by-element too, and lists which contain
+	/// themselves are supported.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The object to compare against.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, [2, [3]]].$eql_deep([1, [2, [3]]]));
+	/// assert(![1, [2, [3]]].$eql_deep([1, [2, [4]]]));
+	/// ```
+	pub fn qs_eql_deep(this: &Object, args: Args) -> crate::Result<bool> {
+		eql_deep_obj(this, args.arg(0)?, &mut vec![])
+	}
+
 	/// Add an element to the back of the list, returning the list.
 	///
 	/// # Arguments
@@ -620,9 +983,9 @@ impl List {
 	/// ```quest
 	/// $list = ["a", "b"];
 	///
-	/// assert(list.$pop() == "a");
-	/// assert(list.$pop() == "b");
-	/// assert(list.$pop() == null);
+	/// assert(list.$shift() == "a");
+	/// assert(list.$shift() == "b");
+	/// assert(list.$shift() == null);
 	/// assert(!list);
 	/// ```
 	#[inline]
@@ -700,6 +1063,34 @@ impl List {
 		Ok(this.clone())
 	}
 
+	/// Returns a new list containing this one repeated the (floored) first argument's times.
+	///
+	/// # Arguments
+	///
+	/// 1. (required, `@num`) The amount of times to repeat the list. It must not be negative.
+	///
+	/// # Errors
+	///
+	/// If the result would have more than [`max_repeat_len`](crate::utils::max_repeat_len)
+	/// elements, a [`TooLarge`](ValueError::TooLarge) error is returned.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2] * 3 == [1, 2, 1, 2, 1, 2]);
+	/// assert([1, 2] * 0 == []);
+	/// ```
+	pub fn qs_mul(&self, args: Args) -> crate::Result<List> {
+		let count = args.arg_as::<Number>(0, "*")?.floor();
+
+		if count.is_negative() {
+			return Err(ValueError::Messaged(format!("bad repeat count '{}'", count)).into());
+		}
+
+		let len = crate::utils::repeat_len(self.len(), count as usize)?;
+
+		Ok(self.iter().cycle().take(len).cloned().collect::<Vec<_>>().into())
+	}
+
 	/// Get the intersection of two lists, i.e. the common elements
 	///
 	/// # Arguments
@@ -821,40 +1212,1555 @@ impl List {
 
 		Ok(this.clone())
 	}
-}
 
-impl_object_type!{
-for List [(parents super::Basic) (convert "@list")]:
-	"@text" => method List::qs_at_text,
-	"__inspect__" => method List::qs___inspect__,
-	"@bool" => method List::qs_at_bool,
-	"@list" => function List::qs_at_list,
-	"clone" => method List::qs_clone,
+	/// Returns a new list of the results of calling a function with each element.
+	///
+	/// If the function returns an error, mapping stops and the error is returned.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to call with each element.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$map({ _0 * 2 }) == [2, 4, 6]);
+	/// assert([].$map({ _0 * 2 }) == []);
+	/// ```
+	pub fn qs_map(&self, args: Args) -> crate::Result<List> {
+		let func = args.arg(0)?;
 
-	"clear" => function List::qs_clear,
-	"find" => method List::qs_find,
-	"len" => method List::qs_len,
+		self.iter()
+			.map(|obj| func.call_attr_lit("()", &[obj]))
+			.collect::<crate::Result<Vec<_>>>()
+			.map(List::from)
+	}
 
-	"get" => method List::qs_get,
-	"set" => function List::qs_set,
-	"join" => method List::qs_join,
+	/// Calls a function with each element in order, returning the list.
+	///
+	/// The function's return values are ignored. If it returns an error, iteration stops and the
+	/// error is returned.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to call with each element.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $total = 0;
+	/// [1, 2, 3].$each({ total += _0 });
+	/// assert(total == 6);
+	/// ```
+	pub fn qs_each(this: &Object, args: Args) -> crate::Result<Object> {
+		let func = args.arg(0)?;
+		let list = this.try_downcast_ref::<Self>()?.clone();
 
-	"<<" => function List::qs_push,
-	"push" => function List::qs_push,
-	"pop" => method_mut List::qs_pop,
-	"unshift" => function List::qs_unshift,
-	"shift" => method_mut List::qs_shift,
+		for ele in list.iter() {
+			func.call_attr_lit("()", &[ele])?;
+		}
 
-	"=="    => method List::qs_eql,
-	"+" => method List::qs_add,
-	"+=" => function List::qs_add_assign,
-	"-" => method List::qs_sub,
-	"-=" => function List::qs_sub_assign,
-	"&" => method List::qs_bitand,
-	"&=" => function List::qs_bitand_assign,
-	"|" => method List::qs_bitor,
-	"|=" => function List::qs_bitor_assign,
-	"^" => method List::qs_bitxor,
-	"^=" => function List::qs_bitxor_assign,
-}
+		Ok(this.clone())
+	}
+
+	/// Calls a function with each element and its index in order, returning the list.
+	///
+	/// This is the same as `each`, except the index is passed as a second argument.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $total = 0;
+	/// [1, 2, 3].$each_with_index({ total += _0 * _1 });
+	/// assert(total == 8);
+	/// ```
+	pub fn qs_each_with_index(this: &Object, args: Args) -> crate::Result<Object> {
+		let func = args.arg(0)?;
+		let list = this.try_downcast_ref::<Self>()?.clone();
+
+		for (idx, ele) in list.iter().enumerate() {
+			func.call_attr_lit("()", &[ele, &idx.into()])?;
+		}
+
+		Ok(this.clone())
+	}
 
+	/// Returns a new list of the elements for which a function returns a truthy value.
+	///
+	/// The function's return value is converted via its `@bool` attribute. Kept elements are the
+	/// same objects as in the original list, not copies.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to call with each element.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3, 4].$filter({ _0 % 2 == 0 }) == [2, 4]);
+	/// assert([1, 2, 3, 4].$filter({ _0 % 2 }) == [1, 3]);
+	/// ```
+	pub fn qs_filter(&self, args: Args) -> crate::Result<List> {
+		let func = args.arg(0)?;
+		let mut kept = Vec::new();
+
+		for obj in self.iter() {
+			if func.call_attr_lit("()", &[obj])?.downcast_call::<Boolean>()?.into() {
+				kept.push(obj.clone());
+			}
+		}
+
+		Ok(kept.into())
+	}
+
+	/// Returns the first element, or [`Null`](crate::types::Null) if the list is empty.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$first() == 1);
+	/// assert([].$first() == null);
+	/// ```
+	#[inline]
+	pub fn qs_first(&self, _: Args) -> Result<Object, !> {
+		Ok(self.0.first().cloned().unwrap_or_default())
+	}
+
+	/// Returns the last element, or [`Null`](crate::types::Null) if the list is empty.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$last() == 3);
+	/// assert([].$last() == null);
+	/// ```
+	#[inline]
+	pub fn qs_last(&self, _: Args) -> Result<Object, !> {
+		Ok(self.0.last().cloned().unwrap_or_default())
+	}
+
+	/// Returns a new list of every element except the first.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$rest() == [2, 3]);
+	/// assert([].$rest() == []);
+	/// ```
+	#[inline]
+	pub fn qs_rest(&self, _: Args) -> Result<List, !> {
+		Ok(self.0.get(1..).unwrap_or_default().to_vec().into())
+	}
+
+	/// Returns a new list of every element except the last.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$init() == [1, 2]);
+	/// assert([].$init() == []);
+	/// ```
+	#[inline]
+	pub fn qs_init(&self, _: Args) -> Result<List, !> {
+		Ok(self.0[..self.len().saturating_sub(1)].to_vec().into())
+	}
+
+	/// Returns a new list of the first `n` elements.
+	///
+	/// Negative counts are treated as zero, and counts past the end take the whole list.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) How many elements to take.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$take(2) == [1, 2]);
+	/// assert([1, 2, 3].$take(10) == [1, 2, 3]);
+	/// assert([1, 2, 3].$take(-1) == []);
+	/// ```
+	pub fn qs_take(&self, args: Args) -> crate::Result<List> {
+		let count = clamp_count(args.arg_as::<Number>(0, "take")?, self.len());
+
+		Ok(self.0[..count].to_vec().into())
+	}
+
+	/// Returns a new list without the first `n` elements.
+	///
+	/// Negative counts are treated as zero, and counts past the end drop the whole list.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) How many elements to drop.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$drop(2) == [3]);
+	/// assert([1, 2, 3].$drop(10) == []);
+	/// assert([1, 2, 3].$drop(-1) == [1, 2, 3]);
+	/// ```
+	pub fn qs_drop(&self, args: Args) -> crate::Result<List> {
+		let count = clamp_count(args.arg_as::<Number>(0, "drop")?, self.len());
+
+		Ok(self.0[count..].to_vec().into())
+	}
+
+	/// Returns a new list of the leading elements for which a function returns a truthy value.
+	///
+	/// The function's return value is converted via its `@bool` attribute, and it's not called
+	/// again after the first falsey result.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to call with each element.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3, 1].$take_while({ _0 < 3 }) == [1, 2]);
+	/// ```
+	pub fn qs_take_while(&self, args: Args) -> crate::Result<List> {
+		let count = self.count_while(args.arg(0)?)?;
+
+		Ok(self.0[..count].to_vec().into())
+	}
+
+	/// Returns a new list without the leading elements for which a function returns a truthy
+	/// value.
+	///
+	/// The function's return value is converted via its `@bool` attribute, and it's not called
+	/// again after the first falsey result.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to call with each element.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3, 1].$drop_while({ _0 < 3 }) == [3, 1]);
+	/// ```
+	pub fn qs_drop_while(&self, args: Args) -> crate::Result<List> {
+		let count = self.count_while(args.arg(0)?)?;
+
+		Ok(self.0[count..].to_vec().into())
+	}
+
+	/// Splits the list into consecutive, non-overlapping lists of `size` elements.
+	///
+	/// If the list doesn't divide evenly, the last chunk is shorter.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The size of each chunk; must be positive.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3, 4, 5].$chunk(2) == [[1, 2], [3, 4], [5]]);
+	/// ```
+	pub fn qs_chunk(&self, args: Args) -> crate::Result<List> {
+		let size = group_size(args.arg_as::<Number>(0, "chunk")?, "chunk")?;
+
+		Ok(self.0.chunks(size)
+			.map(|chunk| List::from(chunk.to_vec()).into())
+			.collect::<Vec<Object>>()
+			.into())
+	}
+
+	/// Returns every contiguous, overlapping run of `size` elements, in order.
+	///
+	/// If `size` is larger than the list, there are no windows.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The size of each window; must be positive.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3, 4].$windows(3) == [[1, 2, 3], [2, 3, 4]]);
+	/// assert([1, 2].$windows(3) == []);
+	/// ```
+	pub fn qs_windows(&self, args: Args) -> crate::Result<List> {
+		let size = group_size(args.arg_as::<Number>(0, "windows")?, "windows")?;
+
+		Ok(self.0.windows(size)
+			.map(|window| List::from(window.to_vec()).into())
+			.collect::<Vec<Object>>()
+			.into())
+	}
+
+	fn count_while(&self, func: &Object) -> crate::Result<usize> {
+		for (idx, obj) in self.iter().enumerate() {
+			if !func.call_attr_lit("()", &[obj])?.downcast_call::<Boolean>()?.into_inner() {
+				return Ok(idx);
+			}
+		}
+
+		Ok(self.len())
+	}
+
+	/// Combines all elements into a single value by repeatedly calling a function.
+	///
+	/// The function is called with the accumulator and the next element, and its return value
+	/// becomes the new accumulator. If no initial value is given, the first element is used and
+	/// folding starts at the second; an empty list without an initial value is an error.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The function to call with the accumulator and each element.
+	/// 2. (optional) The initial accumulator.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$reduce({ _0 + _1 }) == 6);
+	/// assert([1, 2, 3].$reduce({ _0 + _1 }, 10) == 16);
+	/// assert([].$reduce({ _0 + _1 }, 0) == 0);
+	/// ```
+	pub fn qs_reduce(&self, args: Args) -> crate::Result<Object> {
+		let func = args.arg(0)?;
+		let mut iter = self.iter();
+
+		let init =
+			if let Ok(init) = args.arg(1) {
+				init.clone()
+			} else if let Some(first) = iter.next() {
+				first.clone()
+			} else {
+				return Err(ValueError::Messaged(
+					"reduce of empty list with no initial value".to_string()).into())
+			};
+
+		iter.try_fold(init, |acc, obj| func.call_attr_lit("()", &[&acc, obj]))
+	}
+
+	/// Returns a new list with the elements in reverse order.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = [1, 2, 3];
+	///
+	/// assert(list.$reversed() == [3, 2, 1]);
+	/// assert(list == [1, 2, 3]);
+	/// ```
+	pub fn qs_reversed(&self, _: Args) -> Result<List, !> {
+		Ok(self.iter().rev().cloned().collect::<Vec<_>>().into())
+	}
+
+	/// Reverses the list in place, returning the list.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = [1, 2, 3];
+	///
+	/// list.$reverse();
+	/// assert(list == [3, 2, 1]);
+	/// ```
+	pub fn qs_reverse(this: &Object, _: Args) -> crate::Result<Object> {
+		this.try_downcast_mut::<Self>()?.0.to_mut().reverse();
+
+		Ok(this.clone())
+	}
+
+	/// Returns a new list with duplicate elements removed, keeping the first occurrence of each.
+	///
+	/// Elements are compared via their `==` attribute.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 1, 3, 2].$unique() == [1, 2, 3]);
+	/// ```
+	pub fn qs_unique(&self, _: Args) -> crate::Result<List> {
+		let mut unique = List::from(Vec::with_capacity(self.len()));
+
+		for ele in self.iter() {
+			if unique.find(ele)?.is_none() {
+				unique.push(ele.clone());
+			}
+		}
+
+		Ok(unique)
+	}
+
+	/// Returns a list of lists, each containing the elements at the same position in `this` and
+	/// the given lists.
+	///
+	/// The result is as long as the shortest list.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The list to zip with.
+	/// 2. (optional, variadic) More lists to zip with.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3].$zip(["a", "b"]) == [[1, "a"], [2, "b"]]);
+	/// assert([1, 2].$zip([3, 4], [5, 6]) == [[1, 3, 5], [2, 4, 6]]);
+	/// ```
+	pub fn qs_zip(&self, args: Args) -> crate::Result<List> {
+		let mut lists = vec![self.clone()];
+
+		for idx in 0..args.as_ref().len().max(1) {
+			lists.push(args.arg_as::<Self>(idx, "zip")?);
+		}
+
+		let len = lists.iter().map(List::len).min().unwrap_or_default();
+
+		Ok((0..len)
+			.map(|idx| List::from(lists.iter().map(|list| list.0[idx].clone()).collect::<Vec<_>>()).into())
+			.collect::<Vec<Object>>()
+			.into())
+	}
+
+	/// Returns a list of `[index, element]` pairs.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert(["a", "b"].$enumerate() == [[0, "a"], [1, "b"]]);
+	/// ```
+	pub fn qs_enumerate(&self, _: Args) -> Result<List, !> {
+		Ok(self.iter()
+			.enumerate()
+			.map(|(idx, ele)| List::from(vec![Object::from(idx), ele.clone()]).into())
+			.collect::<Vec<Object>>()
+			.into())
+	}
+
+	/// Returns a new list with the elements of nested lists spliced in.
+	///
+	/// # Arguments
+	///
+	/// 1. (optional) How many levels to flatten. Defaults to `1`; `Number::INF` flattens fully.
+	///    Negative and `NaN` depths are an error.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, [2, [3, [4]]]].$flatten() == [1, 2, [3, [4]]]);
+	/// assert([1, [2, [3, [4]]]].$flatten(2) == [1, 2, 3, [4]]);
+	/// assert([1, [2, [3, [4]]]].$flatten(Number::INF) == [1, 2, 3, 4]);
+	/// ```
+	pub fn qs_flatten(&self, args: Args) -> crate::Result<List> {
+		let depth =
+			if let Ok(depth) = args.arg(0) {
+				let depth = depth.downcast_call::<Number>()?;
+
+				if depth.is_nan() || depth < Number::ZERO {
+					return Err(ValueError::Messaged(format!("invalid flatten depth: {}", depth)).into());
+				}
+
+				usize::try_from(depth.floor()).unwrap_or(usize::MAX)
+			} else {
+				1
+			};
+
+		self.flatten(depth)
+	}
+
+	/// Removes consecutive duplicate elements in place, returning the list.
+	///
+	/// Elements are compared via their `==` attribute. Unlike `unique`, only duplicates that are
+	/// next to each other are removed.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = [1, 1, 2, 1, 1];
+	///
+	/// list.$dedup();
+	/// assert(list == [1, 2, 1]);
+	/// ```
+	pub fn qs_dedup(this: &Object, _: Args) -> crate::Result<Object> {
+		let list = this.try_downcast_ref::<Self>()?.clone();
+		let mut deduped = Vec::<Object>::with_capacity(list.len());
+
+		for ele in list.iter() {
+			match deduped.last() {
+				Some(last) if last.eq_obj(ele)? => {},
+				_ => deduped.push(ele.clone())
+			}
+		}
+
+		*this.try_downcast_mut::<Self>()? = deduped.into();
+
+		Ok(this.clone())
+	}
+
+	/// Adds all the elements together, after converting each to a [`Number`].
+	///
+	/// An empty list's sum is `0`.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([1, 2, 3.5].$sum() == 6.5);
+	/// assert(["1", 2].$sum() == 3);
+	/// assert([].$sum() == 0);
+	/// ```
+	pub fn qs_sum(&self, _: Args) -> crate::Result<Number> {
+		self.iter().try_fold(Number::ZERO, |sum, ele| Ok(sum + ele.downcast_call::<Number>()?))
+	}
+
+	/// Multiplies all the elements together, after converting each to a [`Number`].
+	///
+	/// An empty list's product is `1`.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([2, 3, 4].$product() == 24);
+	/// assert(["2", true].$product() == 2);
+	/// assert([].$product() == 1);
+	/// ```
+	pub fn qs_product(&self, _: Args) -> crate::Result<Number> {
+		self.iter().try_fold(Number::ONE, |prod, ele| Ok(prod * ele.downcast_call::<Number>()?))
+	}
+
+	/// Returns the first element that compares as `is_better` against every other one.
+	fn find_extreme(&self, is_better: Ordering) -> crate::Result<Object> {
+		let mut iter = self.iter();
+		let mut best =
+			if let Some(first) = iter.next() {
+				first
+			} else {
+				return Ok(Object::default())
+			};
+
+		for ele in iter {
+			if compare(ele, best)? == is_better {
+				best = ele;
+			}
+		}
+
+		Ok(best.clone())
+	}
+
+	/// Returns the smallest element, or [`Null`](crate::types::Null) if the list is empty.
+	///
+	/// Elements are compared via their `<=>` attribute; if several are equally small, the first
+	/// one is returned.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([3, 1, 2].$min() == 1);
+	/// assert(["b", "a"].$min() == "a");
+	/// assert([].$min() == null);
+	/// ```
+	#[inline]
+	pub fn qs_min(&self, _: Args) -> crate::Result<Object> {
+		self.find_extreme(Ordering::Less)
+	}
+
+	/// Returns the largest element, or [`Null`](crate::types::Null) if the list is empty.
+	///
+	/// Elements are compared via their `<=>` attribute; if several are equally large, the first
+	/// one is returned.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert([3, 1, 2].$max() == 3);
+	/// assert(["b", "a"].$max() == "b");
+	/// assert([].$max() == null);
+	/// ```
+	#[inline]
+	pub fn qs_max(&self, _: Args) -> crate::Result<Object> {
+		self.find_extreme(Ordering::Greater)
+	}
+
+	/// Returns a sorted copy of the list.
+	///
+	/// Elements are compared with their `<=>` attribute, unless a comparison function is given.
+	/// The sort is stable, and any error raised while comparing is returned.
+	///
+	/// # Arguments
+	///
+	/// 1. (optional) A function that's called with two elements and returns a negative number,
+	///    zero, or a positive number if the first is less than, equal to, or greater than the
+	///    second.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = [3, 1, 2];
+	///
+	/// assert(list.$sorted() == [1, 2, 3]);
+	/// assert(list.$sorted({ _1 <=> _0 }) == [3, 2, 1]);
+	/// assert(list == [3, 1, 2]);
+	/// ```
+	pub fn qs_sorted(&self, args: Args) -> crate::Result<List> {
+		if let Ok(func) = args.arg(0) {
+			self.try_sorted_by(|lhs, rhs| to_ordering(&func.call_attr_lit("()", &[lhs, rhs])?))
+		} else {
+			self.try_sorted_by(compare)
+		}
+	}
+
+	/// Sorts the list in place, returning the list.
+	///
+	/// This accepts the same arguments as `sorted`.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = ["b", "c", "a"];
+	///
+	/// list.$sort();
+	/// assert(list == ["a", "b", "c"]);
+	/// ```
+	pub fn qs_sort(this: &Object, args: Args) -> crate::Result<Object> {
+		let sorted = this.try_downcast_ref::<Self>()?.qs_sorted(args)?;
+
+		*this.try_downcast_mut::<Self>()? = sorted;
+
+		Ok(this.clone())
+	}
+
+	/// Sorts the list in place by the key a function returns for each element, returning the list.
+	///
+	/// The function is only called once for each element, and the keys are compared with their
+	/// `<=>` attribute. The sort is stable, so elements with equal keys keep their order.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) A function that's called with each element, and returns its key.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $list = [[2, "a"], [1, "b"], [2, "c"]];
+	///
+	/// list.$sort_by_key({ _0.$get(0) });
+	/// assert(list == [[1, "b"], [2, "a"], [2, "c"]]);
+	/// ```
+	pub fn qs_sort_by_key(this: &Object, args: Args) -> crate::Result<Object> {
+		let func = args.arg(0)?;
+		let sorted = this.try_downcast_ref::<Self>()?
+			.try_sorted_by_key(|obj| func.call_attr_lit("()", &[obj]))?;
+
+		*this.try_downcast_mut::<Self>()? = sorted;
+
+		Ok(this.clone())
+	}
+
+	/// Groups elements by the key a function returns for them.
+	///
+	/// A [`Map`] from each distinct key to a list of the elements with that key is returned.
+	/// Elements within each group are in the same order they were in this list.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) A function that's called with each element, and returns its key.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $groups = [1, 2, 3, 4, 5].$group_by({ _0 % 2 });
+	///
+	/// assert(groups.$len() == 2);
+	/// assert(groups[1] == [1, 3, 5]);
+	/// assert(groups[0] == [2, 4]);
+	/// ```
+	pub fn qs_group_by(&self, args: Args) -> crate::Result<Map> {
+		let func = args.arg(0)?;
+		let groups = Map::new();
+
+		for ele in self.iter() {
+			let key = func.call_attr_lit("()", &[ele])?;
+
+			if let Some(group) = groups.get(&key)? {
+				group.try_downcast_mut::<Self>()?.push(ele.clone());
+			} else {
+				groups.insert(key, Object::from(List::from(vec![ele.clone()])))?;
+			}
+		}
+
+		Ok(groups)
+	}
+}
+
+impl List {
+	/// Format this as a JSON array, converting each element with its `to_json` method.
+	pub fn to_json(&self) -> crate::Result<String> {
+		let elements = self.iter()
+			.map(|ele| ele.call_attr_lit("to_json", &[])?.downcast_call::<Text>())
+			.collect::<crate::Result<Vec<_>>>()?;
+
+		Ok(format!("[{}]", elements.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join(",")))
+	}
+}
+
+impl_object_type!{
+for List [(parents super::Basic) (convert "@list")]:
+	"@text" => method List::qs_at_text,
+	"__inspect__" => method List::qs___inspect__,
+	"@bool" => method List::qs_at_bool,
+	"@num" => method List::qs_at_num,
+	"to_json" => method List::qs_to_json,
+	"@list" => function List::qs_at_list,
+	"clone" => method List::qs_clone,
+	"new" => function List::qs_new,
+
+	"clear" => function List::qs_clear,
+	"find" => method List::qs_find,
+	"contains" => method List::qs_contains,
+	"index_of" => method List::qs_index_of,
+	"len" => method List::qs_len,
+
+	"get" => method List::qs_get,
+	"[]" => method List::qs_index,
+	"[]=" => function List::qs_index_assign,
+	"set" => function List::qs_set,
+	"join" => method List::qs_join,
+	"each" => function List::qs_each,
+	"each_with_index" => function List::qs_each_with_index,
+	"map" => method List::qs_map,
+	"filter" => method List::qs_filter,
+	"reduce" => method List::qs_reduce,
+	"sort" => function List::qs_sort,
+	"sort_by_key" => function List::qs_sort_by_key,
+	"group_by" => method List::qs_group_by,
+	"sorted" => method List::qs_sorted,
+	"sum" => method List::qs_sum,
+	"product" => method List::qs_product,
+	"min" => method List::qs_min,
+	"max" => method List::qs_max,
+	"reverse" => function List::qs_reverse,
+	"reversed" => method List::qs_reversed,
+	"unique" => method List::qs_unique,
+	"flatten" => method List::qs_flatten,
+	"zip" => method List::qs_zip,
+	"chunk" => method List::qs_chunk,
+	"windows" => method List::qs_windows,
+	"first" => method List::qs_first,
+	"last" => method List::qs_last,
+	"rest" => method List::qs_rest,
+	"init" => method List::qs_init,
+	"take" => method List::qs_take,
+	"drop" => method List::qs_drop,
+	"take_while" => method List::qs_take_while,
+	"drop_while" => method List::qs_drop_while,
+	"enumerate" => method List::qs_enumerate,
+	"dedup" => function List::qs_dedup,
+
+	"<<" => function List::qs_push,
+	"push" => function List::qs_push,
+	"pop" => method_mut List::qs_pop,
+	"unshift" => function List::qs_unshift,
+	"shift" => method_mut List::qs_shift,
+
+	"=="    => method List::qs_eql,
+	"eql_deep" => function List::qs_eql_deep,
+	"+" => method List::qs_add,
+	"+=" => function List::qs_add_assign,
+	"-" => method List::qs_sub,
+	"-=" => function List::qs_sub_assign,
+	"*" => method List::qs_mul,
+	"&" => method List::qs_bitand,
+	"&=" => function List::qs_bitand_assign,
+	"|" => method List::qs_bitor,
+	"|=" => function List::qs_bitor_assign,
+	"^" => method List::qs_bitxor,
+	"^=" => function List::qs_bitxor_assign,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::types::RustFn;
+
+	fn to_numbers(list: List) -> Vec<Number> {
+		list.iter()
+			.map(|obj| *obj.downcast_ref::<Number>().expect("not a number"))
+			.collect()
+	}
+
+	fn numbers(nums: &[i32]) -> List {
+		nums.iter().map(|&num| Object::from(num)).collect::<Vec<_>>().into()
+	}
+
+	#[test]
+	fn index_assign_argument_count() {
+		use crate::error::TypeError;
+
+		let list = Object::from(numbers(&[1, 2]));
+
+		assert_eq!(
+			list.call_attr_lit("[]=", &[]).unwrap_err(),
+			TypeError::ArgumentCount { method: Some("List::[]="), missing: 0, got: 0 }.into()
+		);
+		assert_eq!(
+			list.call_attr_lit("[]=", &[&0.into()]).unwrap_err(),
+			TypeError::ArgumentCount { method: Some("List::[]="), missing: 1, got: 1 }.into()
+		);
+
+		// called directly, there's no way to know the method's name
+		assert_eq!(
+			List::qs_index_assign(&list, args!(0)).unwrap_err(),
+			TypeError::ArgumentCount { method: None, missing: 1, got: 1 }.into()
+		);
+	}
+
+	#[test]
+	fn to_json() {
+		let nested = List::from(vec![
+			Object::from(1),
+			Object::from(2.5),
+			Object::from(Text::from("a\"b")),
+			Object::from(List::from(vec![Object::from(true), Object::default(), numbers(&[]).into()])),
+		]);
+
+		assert_eq!(nested.to_json().unwrap(), r#"[1,2.5,"a\"b",[true,null,[]]]"#);
+		assert_eq!(numbers(&[]).qs_to_json(args!()).unwrap().as_ref(), "[]");
+
+		let with_nan = List::from(vec![Object::from(1), Object::from(List::from(vec![Number::NAN.into()]))]);
+		assert!(with_nan.to_json().is_err());
+	}
+
+	#[test]
+	fn at_num() {
+		assert_eq!(numbers(&[]).qs_at_num(args!()).unwrap(), Number::ZERO);
+		assert_eq!(numbers(&[4, 5, 6]).qs_at_num(args!()).unwrap(), Number::from(3));
+
+		let list = Object::from(numbers(&[4, 5]));
+		assert_eq!(list.downcast_call::<Number>().unwrap(), Number::from(2));
+		assert_eq!(Object::from(crate::types::Null).downcast_call::<Number>().unwrap(), Number::ZERO);
+	}
+
+	#[test]
+	fn eql_deep() {
+		let nested = |last: i32| List::from(vec![
+			Object::from(1),
+			Object::from(List::from(vec![Object::from(2), Object::from(numbers(&[3, last]))]))
+		]);
+
+		assert!(nested(4).eql_deep(&nested(4)).unwrap());
+		assert!(!nested(4).eql_deep(&nested(5)).unwrap());
+		assert!(!nested(4).eql_deep(&numbers(&[1])).unwrap());
+
+		let lhs = Object::from(nested(4));
+		assert!(List::qs_eql_deep(&lhs, args!(nested(4))).unwrap());
+		assert!(!List::qs_eql_deep(&lhs, args!(nested(5))).unwrap());
+		assert!(!List::qs_eql_deep(&lhs, args!(1)).unwrap());
+	}
+
+	#[test]
+	fn eql_deep_cyclic() {
+		let cyclic = |first: i32| {
+			let list = Object::from(numbers(&[first]));
+			List::qs_push(&list, args!(list.clone())).unwrap();
+			list
+		};
+
+		let (one, other_one, two) = (cyclic(1), cyclic(1), cyclic(2));
+
+		assert!(List::qs_eql_deep(&one, args!(one.clone())).unwrap());
+		assert!(List::qs_eql_deep(&one, args!(other_one)).unwrap());
+		assert!(!List::qs_eql_deep(&one, args!(two)).unwrap());
+	}
+
+	#[test]
+	fn take_and_drop() {
+		let list = numbers(&[1, 2, 3]);
+
+		assert_eq!(to_numbers(list.qs_take(args!(2)).unwrap()), to_numbers(numbers(&[1, 2])));
+		assert_eq!(to_numbers(list.qs_drop(args!(2)).unwrap()), to_numbers(numbers(&[3])));
+
+		assert_eq!(to_numbers(list.qs_take(args!(10)).unwrap()), to_numbers(numbers(&[1, 2, 3])));
+		assert!(list.qs_drop(args!(10)).unwrap().is_empty());
+
+		assert!(list.qs_take(args!(-1)).unwrap().is_empty());
+		assert_eq!(to_numbers(list.qs_drop(args!(-1)).unwrap()), to_numbers(numbers(&[1, 2, 3])));
+
+		assert_eq!(to_numbers(list.qs_take(args!(1.9)).unwrap()), to_numbers(numbers(&[1])));
+		assert!(list.qs_take(args!()).is_err());
+	}
+
+	#[test]
+	fn take_while_and_drop_while() {
+		let less_than_three = Object::from(RustFn::new("less_than_three", |this, _| {
+			this.call_attr_lit("<", &[&3.into()])
+		}));
+		let list = numbers(&[1, 2, 3, 1]);
+
+		assert_eq!(
+			to_numbers(list.qs_take_while(args!(less_than_three.clone())).unwrap()),
+			to_numbers(numbers(&[1, 2]))
+		);
+		assert_eq!(
+			to_numbers(list.qs_drop_while(args!(less_than_three)).unwrap()),
+			to_numbers(numbers(&[3, 1]))
+		);
+
+		let always = Object::from(RustFn::new("always", |_, _| Ok(true.into())));
+		assert_eq!(to_numbers(list.qs_take_while(args!(always.clone())).unwrap()), to_numbers(list.clone()));
+		assert!(list.qs_drop_while(args!(always)).unwrap().is_empty());
+	}
+
+	fn to_lists(list: List) -> Vec<List> {
+		list.iter()
+			.map(|obj| obj.downcast_ref::<List>().expect("not a list").clone())
+			.collect()
+	}
+
+	fn to_number_lists(list: List) -> Vec<Vec<Number>> {
+		to_lists(list).into_iter().map(to_numbers).collect()
+	}
+
+	#[test]
+	fn from_iter() {
+		let list = (1..=3).map(Object::from).collect::<List>();
+		assert_eq!(to_numbers(list), to_numbers(numbers(&[1, 2, 3])));
+		assert!(std::iter::empty().collect::<List>().is_empty());
+	}
+
+	#[test]
+	fn new() {
+		use crate::types::{Null, ObjectType};
+
+		let list = List::qs_new(&List::mapping(), args!(1, "two", true, Null)).unwrap();
+		assert_eq!(list.len(), 4);
+
+		let mut iter = list.iter();
+		assert_eq!(*iter.next().unwrap().downcast_ref::<Number>().unwrap(), Number::from(1));
+		assert_eq!(*iter.next().unwrap().downcast_ref::<Text>().unwrap(), Text::from("two"));
+		assert_eq!(*iter.next().unwrap().downcast_ref::<Boolean>().unwrap(), Boolean::from(true));
+		assert!(iter.next().unwrap().is_a::<Null>());
+		assert!(iter.next().is_none());
+
+		assert!(List::qs_new(&List::mapping(), args!()).unwrap().is_empty());
+	}
+
+	#[test]
+	fn mul() {
+		assert_eq!(
+			to_numbers(numbers(&[1, 2]).qs_mul(args!(3)).unwrap()),
+			to_numbers(numbers(&[1, 2, 1, 2, 1, 2]))
+		);
+		assert_eq!(
+			to_numbers(numbers(&[1, 2]).qs_mul(args!(1.9)).unwrap()),
+			to_numbers(numbers(&[1, 2]))
+		);
+		assert!(numbers(&[1, 2]).qs_mul(args!(0)).unwrap().is_empty());
+		assert!(numbers(&[]).qs_mul(args!(4)).unwrap().is_empty());
+		assert!(numbers(&[1]).qs_mul(args!(-1)).is_err());
+	}
+
+	#[test]
+	fn mul_too_large() {
+		use crate::utils::{set_max_repeat_len, DEFAULT_MAX_REPEAT_LEN};
+
+		set_max_repeat_len(6);
+
+		assert_eq!(numbers(&[1, 2, 3]).qs_mul(args!(2)).unwrap().len(), 6);
+		assert_eq!(
+			numbers(&[1, 2, 3]).qs_mul(args!(3)).unwrap_err(),
+			ValueError::TooLarge { size: 9, max: 6 }.into()
+		);
+
+		set_max_repeat_len(DEFAULT_MAX_REPEAT_LEN);
+	}
+
+	#[test]
+	fn chunk() {
+		assert_eq!(
+			to_number_lists(numbers(&[1, 2, 3, 4]).qs_chunk(args!(2)).unwrap()),
+			vec![to_numbers(numbers(&[1, 2])), to_numbers(numbers(&[3, 4]))]
+		);
+		assert_eq!(
+			to_number_lists(numbers(&[1, 2, 3, 4, 5]).qs_chunk(args!(2)).unwrap()),
+			vec![to_numbers(numbers(&[1, 2])), to_numbers(numbers(&[3, 4])), to_numbers(numbers(&[5]))]
+		);
+		assert_eq!(
+			to_number_lists(numbers(&[1, 2]).qs_chunk(args!(5)).unwrap()),
+			vec![to_numbers(numbers(&[1, 2]))]
+		);
+		assert!(numbers(&[]).qs_chunk(args!(2)).unwrap().is_empty());
+	}
+
+	#[test]
+	fn windows() {
+		assert_eq!(
+			to_number_lists(numbers(&[1, 2, 3, 4]).qs_windows(args!(3)).unwrap()),
+			vec![to_numbers(numbers(&[1, 2, 3])), to_numbers(numbers(&[2, 3, 4]))]
+		);
+		assert!(numbers(&[1, 2]).qs_windows(args!(3)).unwrap().is_empty());
+	}
+
+	#[test]
+	fn zero_size_groups() {
+		let list = numbers(&[1, 2, 3]);
+
+		assert_eq!(
+			list.qs_chunk(args!(0)).unwrap_err(),
+			ValueError::Messaged("chunk size must be positive, not 0".to_string()).into()
+		);
+		assert_eq!(
+			list.qs_windows(args!(0)).unwrap_err(),
+			ValueError::Messaged("windows size must be positive, not 0".to_string()).into()
+		);
+		assert!(list.qs_chunk(args!(-2)).is_err());
+	}
+
+	#[test]
+	fn zip() {
+		let zipped = to_lists(numbers(&[1, 2, 3]).qs_zip(args!(numbers(&[4, 5]))).unwrap());
+
+		assert_eq!(zipped.len(), 2);
+		assert_eq!(to_numbers(zipped[0].clone()), to_numbers(numbers(&[1, 4])));
+		assert_eq!(to_numbers(zipped[1].clone()), to_numbers(numbers(&[2, 5])));
+
+		let zipped = to_lists(numbers(&[1, 2]).qs_zip(args!(numbers(&[3, 4, 5]), numbers(&[6]))).unwrap());
+		assert_eq!(zipped.len(), 1);
+		assert_eq!(to_numbers(zipped[0].clone()), to_numbers(numbers(&[1, 3, 6])));
+
+		assert!(numbers(&[1, 2]).qs_zip(args!(numbers(&[]))).unwrap().is_empty());
+		assert!(numbers(&[1, 2]).qs_zip(args!()).is_err());
+	}
+
+	#[test]
+	fn enumerate() {
+		let list = List::from(vec![Object::from(Text::from("a")), Object::from(Text::from("b"))]);
+		let pairs = to_lists(list.qs_enumerate(args!()).unwrap());
+
+		assert_eq!(pairs.len(), 2);
+		for (idx, pair) in pairs.iter().enumerate() {
+			assert_eq!(pair.len(), 2);
+			assert_eq!(*pair.get(0).downcast_ref::<Number>().unwrap(), Number::from(idx));
+			assert!(pair.get(1).is_identical(&list.get(idx as isize)));
+		}
+
+		assert!(numbers(&[]).qs_enumerate(args!()).unwrap().is_empty());
+	}
+
+	fn deeply_nested() -> List {
+		// [1, [2, [3, [4]]]]
+		List::from(vec![
+			Object::from(1),
+			Object::from(List::from(vec![
+				Object::from(2),
+				Object::from(List::from(vec![Object::from(3), Object::from(numbers(&[4]))]))
+			]))
+		])
+	}
+
+	#[test]
+	fn flatten_one_level() {
+		let list = List::from(vec![Object::from(numbers(&[1, 2])), Object::from(3), Object::from(numbers(&[]))]);
+		assert_eq!(to_numbers(list.flatten(1).unwrap()), to_numbers(numbers(&[1, 2, 3])));
+
+		let flattened = deeply_nested().qs_flatten(args!()).unwrap();
+		assert_eq!(flattened.len(), 3);
+		assert!(flattened.get(2).downcast_ref::<List>().unwrap().eql_deep(&List::from(vec![
+			Object::from(3), Object::from(numbers(&[4]))
+		])).unwrap());
+	}
+
+	#[test]
+	fn flatten_fully() {
+		assert_eq!(to_numbers(deeply_nested().flatten(usize::MAX).unwrap()), to_numbers(numbers(&[1, 2, 3, 4])));
+		assert_eq!(
+			to_numbers(deeply_nested().qs_flatten(args!(Number::INF)).unwrap()),
+			to_numbers(numbers(&[1, 2, 3, 4]))
+		);
+		assert_eq!(
+			to_numbers(deeply_nested().qs_flatten(args!(3)).unwrap()),
+			to_numbers(numbers(&[1, 2, 3, 4]))
+		);
+	}
+
+	#[test]
+	fn flatten_bad_depth() {
+		assert!(deeply_nested().qs_flatten(args!(Number::NAN)).is_err());
+		assert!(deeply_nested().qs_flatten(args!(-1)).is_err());
+		assert!(deeply_nested().qs_flatten(args!(-0.5)).is_err());
+	}
+
+	#[test]
+	fn flatten_recursive() {
+		let inner = Object::from(numbers(&[1]));
+		let outer = Object::from(List::from(vec![inner.clone()]));
+		inner.downcast_mut::<List>().unwrap().push(outer.clone());
+
+		// `outer` is `[inner]` and `inner` is `[1, outer]`, so `outer` contains itself indirectly.
+		let list = List::from(vec![outer.clone()]);
+		assert!(list.qs_flatten(args!(Number::INF)).is_err());
+
+		// now `outer` is `[inner, outer]`, so it contains itself directly.
+		inner.downcast_mut::<List>().unwrap().pop();
+		outer.downcast_mut::<List>().unwrap().push(outer.clone());
+		assert!(outer.downcast_call::<List>().unwrap().qs_flatten(args!(Number::INF)).is_err());
+	}
+
+	#[test]
+	fn flatten_zero_depth() {
+		let flattened = deeply_nested().qs_flatten(args!(0)).unwrap();
+		assert!(flattened.eql_deep(&deeply_nested()).unwrap());
+	}
+
+	#[test]
+	fn remove() {
+		let mut list = numbers(&[1, 2, 3]);
+
+		assert_eq!(*list.remove(1).unwrap().downcast_ref::<Number>().unwrap(), Number::from(2));
+		assert_eq!(to_numbers(list.clone()), vec![Number::from(1), Number::from(3)]);
+		assert!(list.remove(2).is_none());
+		assert_eq!(list.len(), 2);
+	}
+
+	#[test]
+	fn map() {
+		let double = Object::from(RustFn::new("double", |this, _| {
+			this.call_attr_lit("*", &[&2.into()])
+		}));
+
+		assert_eq!(
+			to_numbers(numbers(&[1, 2, 3]).qs_map(args!(double.clone())).unwrap()),
+			vec![Number::from(2), Number::from(4), Number::from(6)]
+		);
+		assert!(numbers(&[]).qs_map(args!(double)).unwrap().is_empty());
+	}
+
+	#[test]
+	fn map_error_aborts() {
+		let fail_on_two = Object::from(RustFn::new("fail_on_two", |this, _| {
+			if *this.downcast_ref::<Number>().unwrap() == Number::from(2) {
+				Err(crate::error::ValueError::Messaged("two".to_string()).into())
+			} else {
+				Ok(this.clone())
+			}
+		}));
+
+		assert!(numbers(&[1, 2, 3]).qs_map(args!(fail_on_two.clone())).is_err());
+		assert!(numbers(&[1, 3]).qs_map(args!(fail_on_two)).is_ok());
+	}
+
+	#[test]
+	fn filter() {
+		let is_even = Object::from(RustFn::new("is_even", |this, _| {
+			this.call_attr_lit("%", &[&2.into()])?.call_attr_lit("==", &[&0.into()])
+		}));
+
+		let list = List::from(vec![1.into(), 2.into(), 3.into(), 4.into(), 6.into()]);
+		let evens = list.qs_filter(args!(is_even.clone())).unwrap();
+
+		assert_eq!(to_numbers(evens.clone()), vec![Number::from(2), Number::from(4), Number::from(6)]);
+		assert!(evens.iter().next().unwrap().is_identical(list.iter().nth(1).unwrap()));
+		assert!(numbers(&[]).qs_filter(args!(is_even)).unwrap().is_empty());
+	}
+
+	#[test]
+	fn filter_coerces_to_bool() {
+		let is_odd = Object::from(RustFn::new("is_odd", |this, _| this.call_attr_lit("%", &[&2.into()])));
+
+		assert_eq!(
+			to_numbers(numbers(&[1, 2, 3, 4, 5]).qs_filter(args!(is_odd)).unwrap()),
+			vec![Number::from(1), Number::from(3), Number::from(5)]
+		);
+
+		let identity = Object::from(RustFn::new("identity", |this, _| Ok(this.clone())));
+		let list = List::from(vec!["".into(), "a".into(), Object::default(), 0.into(), true.into()]);
+
+		assert_eq!(list.qs_filter(args!(identity)).unwrap().len(), 2);
+	}
+
+	#[test]
+	fn reduce() {
+		let add = Object::from(RustFn::new("add", |this, args| this.call_attr_lit("+", &[args.arg(0)?])));
+
+		let sum = numbers(&[1, 2, 3, 4]).qs_reduce(args!(add.clone())).unwrap();
+		assert_eq!(*sum.downcast_ref::<Number>().unwrap(), Number::from(10));
+
+		let sum = numbers(&[1, 2, 3, 4]).qs_reduce(args!(add.clone(), 10)).unwrap();
+		assert_eq!(*sum.downcast_ref::<Number>().unwrap(), Number::from(20));
+
+		let sum = numbers(&[5]).qs_reduce(args!(add.clone())).unwrap();
+		assert_eq!(*sum.downcast_ref::<Number>().unwrap(), Number::from(5));
+
+		let sum = numbers(&[]).qs_reduce(args!(add.clone(), 0)).unwrap();
+		assert_eq!(*sum.downcast_ref::<Number>().unwrap(), Number::from(0));
+
+		assert!(numbers(&[]).qs_reduce(args!(add)).is_err());
+	}
+
+	#[test]
+	fn sorted() {
+		assert_eq!(
+			to_numbers(numbers(&[3, -1, 2, 2, 0]).qs_sorted(args!()).unwrap()),
+			vec![Number::from(-1), Number::from(0), Number::from(2), Number::from(2), Number::from(3)]
+		);
+
+		let words = List::from(vec!["pear".into(), "apple".into(), "fig".into()])
+			.qs_sorted(args!())
+			.unwrap()
+			.iter()
+			.map(|obj| obj.downcast_ref::<Text>().unwrap().to_string())
+			.collect::<Vec<_>>();
+		assert_eq!(words, vec!["apple", "fig", "pear"]);
+
+		let bools = List::from(vec![true.into(), false.into()]).qs_sorted(args!()).unwrap();
+		assert_eq!(
+			bools.iter().map(|obj| *obj.downcast_ref::<Boolean>().unwrap()).collect::<Vec<_>>(),
+			vec![Boolean::new(false), Boolean::new(true)]
+		);
+
+		assert!(numbers(&[]).qs_sorted(args!()).unwrap().is_empty());
+
+		// NaN isn't ordered, so sorting it is an error rather than a panic.
+		let nan = List::from(vec![1.into(), Number::NAN.into()]);
+		assert!(nan.qs_sorted(args!()).is_err());
+		assert!(nan.qs_min(args!()).is_err());
+	}
+
+	#[test]
+	fn sorted_with_comparator() {
+		let descending = Object::from(RustFn::new("descending", |this, args| {
+			args.arg(0)?.call_attr_lit("<=>", &[this])
+		}));
+
+		assert_eq!(
+			to_numbers(numbers(&[1, 3, 2]).qs_sorted(args!(descending)).unwrap()),
+			vec![Number::from(3), Number::from(2), Number::from(1)]
+		);
+
+		let failing = Object::from(RustFn::new("failing", |_, _| {
+			Err(crate::error::ValueError::Messaged("nope".to_string()).into())
+		}));
+
+		assert!(numbers(&[1, 3, 2]).qs_sorted(args!(failing)).is_err());
+	}
+
+	#[test]
+	fn sort() {
+		let list = Object::from(numbers(&[2, 3, 1]));
+
+		List::qs_sort(&list, args!()).unwrap();
+
+		assert_eq!(
+			to_numbers(list.downcast_ref::<List>().unwrap().clone()),
+			vec![Number::from(1), Number::from(2), Number::from(3)]
+		);
+	}
+
+	#[test]
+	fn sort_by_key() {
+		use crate::types::RustFn;
+		use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+		let record = |key: i32, name: &'static str| Object::from(vec![key.into(), name.into()]);
+		let list = Object::from(List::from(vec![
+			record(3, "c"), record(1, "a"), record(2, "x"), record(1, "b"), record(2, "y")
+		]));
+
+		let calls = Arc::new(AtomicUsize::new(0));
+		let first = {
+			let calls = calls.clone();
+			Object::from(RustFn::new_boxed("first", move |record, _| {
+				calls.fetch_add(1, Ordering::SeqCst);
+				Ok(record.downcast_ref::<List>().unwrap().iter().next().unwrap().clone())
+			}))
+		};
+
+		assert!(List::qs_sort_by_key(&list, args!(first)).unwrap().is_identical(&list));
+		assert_eq!(calls.load(Ordering::SeqCst), 5);
+
+		let names = list.downcast_ref::<List>().unwrap()
+			.iter()
+			.map(|record| record.downcast_ref::<List>().unwrap().iter().nth(1).unwrap()
+				.downcast_ref::<Text>().unwrap().to_string())
+			.collect::<Vec<_>>();
+
+		assert_eq!(names, vec!["a", "b", "x", "y", "c"]);
+
+		assert!(List::qs_sort_by_key(&list, args!()).is_err());
+	}
+
+	#[test]
+	fn group_by() {
+		use crate::types::RustFn;
+
+		let parity = Object::from(RustFn::new("parity", |num, _| {
+			Ok((num.downcast_ref::<Number>().unwrap().floor() % 2).into())
+		}));
+
+		let groups = numbers(&[4, 1, 2, 7, 3, 6]).qs_group_by(args!(parity.clone())).unwrap();
+		assert_eq!(groups.len(), 2);
+
+		let group = |key: i32| to_numbers(
+			groups.get(&key.into()).unwrap().unwrap().downcast_ref::<List>().unwrap().clone());
+
+		assert_eq!(group(0), to_numbers(numbers(&[4, 2, 6])));
+		assert_eq!(group(1), to_numbers(numbers(&[1, 7, 3])));
+
+		assert!(numbers(&[]).qs_group_by(args!(parity)).unwrap().is_empty());
+		assert!(numbers(&[1]).qs_group_by(args!()).is_err());
+	}
+
+	#[test]
+	fn first_last_rest_init() {
+		let first = |list: List| list.qs_first(args!()).unwrap();
+		let last = |list: List| list.qs_last(args!()).unwrap();
+		let as_number = |obj: Object| *obj.downcast_ref::<Number>().unwrap();
+
+		assert!(first(numbers(&[])).is_a::<crate::types::Null>());
+		assert!(last(numbers(&[])).is_a::<crate::types::Null>());
+		assert!(numbers(&[]).qs_rest(args!()).unwrap().is_empty());
+		assert!(numbers(&[]).qs_init(args!()).unwrap().is_empty());
+
+		assert_eq!(as_number(first(numbers(&[7]))), Number::from(7));
+		assert_eq!(as_number(last(numbers(&[7]))), Number::from(7));
+		assert!(numbers(&[7]).qs_rest(args!()).unwrap().is_empty());
+		assert!(numbers(&[7]).qs_init(args!()).unwrap().is_empty());
+
+		assert_eq!(as_number(first(numbers(&[1, 2, 3]))), Number::from(1));
+		assert_eq!(as_number(last(numbers(&[1, 2, 3]))), Number::from(3));
+		assert_eq!(
+			to_numbers(numbers(&[1, 2, 3]).qs_rest(args!()).unwrap()),
+			to_numbers(numbers(&[2, 3]))
+		);
+		assert_eq!(
+			to_numbers(numbers(&[1, 2, 3]).qs_init(args!()).unwrap()),
+			to_numbers(numbers(&[1, 2]))
+		);
+	}
+
+	#[test]
+	fn push_and_unshift() {
+		let list = Object::from(numbers(&[2]));
+
+		assert!(List::qs_push(&list, args!(3)).unwrap().is_identical(&list));
+		assert!(List::qs_unshift(&list, args!(1)).unwrap().is_identical(&list));
+		List::qs_push(&list, args!(4)).unwrap();
+		List::qs_unshift(&list, args!(0)).unwrap();
+
+		assert_eq!(
+			to_numbers(list.downcast_ref::<List>().unwrap().clone()),
+			vec![Number::from(0), Number::from(1), Number::from(2), Number::from(3), Number::from(4)]
+		);
+	}
+
+	#[test]
+	fn pop_and_shift() {
+		let mut list = numbers(&[1, 2, 3]);
+
+		assert_eq!(*list.qs_pop(args!()).unwrap().downcast_ref::<Number>().unwrap(), Number::from(3));
+		assert_eq!(*list.qs_shift(args!()).unwrap().downcast_ref::<Number>().unwrap(), Number::from(1));
+		assert_eq!(to_numbers(list.clone()), vec![Number::from(2)]);
+
+		assert_eq!(*list.qs_shift(args!()).unwrap().downcast_ref::<Number>().unwrap(), Number::from(2));
+		assert!(list.is_empty());
+
+		assert!(list.qs_pop(args!()).unwrap().is_a::<crate::types::Null>());
+		assert!(list.qs_shift(args!()).unwrap().is_a::<crate::types::Null>());
+	}
+
+	#[test]
+	fn index() {
+		let list = numbers(&[1, 2, 3, 4]);
+		let get_num = |args: Args| *list.qs_index(args).unwrap().downcast_ref::<Number>().unwrap();
+
+		assert_eq!(get_num(args!(0)), Number::from(1));
+		assert_eq!(get_num(args!(3)), Number::from(4));
+		assert_eq!(get_num(args!(-1)), Number::from(4));
+		assert_eq!(get_num(args!(-4)), Number::from(1));
+
+		assert!(list.qs_index(args!(4)).unwrap().is_a::<crate::types::Null>());
+		assert!(list.qs_index(args!(-5)).unwrap().is_a::<crate::types::Null>());
+
+		let range = list.qs_index(args!(1, 2)).unwrap().downcast_ref::<List>().unwrap().clone();
+		assert_eq!(to_numbers(range), vec![Number::from(2), Number::from(3)]);
+
+		let range = list.qs_index(args!(-2, -1)).unwrap().downcast_ref::<List>().unwrap().clone();
+		assert_eq!(to_numbers(range), vec![Number::from(3), Number::from(4)]);
+
+		assert!(list.qs_index(args!(7, 9)).unwrap().is_a::<crate::types::Null>());
+	}
+
+	#[test]
+	fn index_assign() {
+		let list = Object::from(numbers(&[1, 2]));
+
+		List::qs_index_assign(&list, args!(0, 5)).unwrap();
+		List::qs_index_assign(&list, args!(-1, 6)).unwrap();
+		assert_eq!(
+			to_numbers(list.downcast_ref::<List>().unwrap().clone()),
+			vec![Number::from(5), Number::from(6)]
+		);
+
+		List::qs_index_assign(&list, args!(4, 7)).unwrap();
+		let list_ref = list.downcast_ref::<List>().unwrap().clone();
+		assert_eq!(list_ref.len(), 5);
+		assert!(list_ref.iter().nth(2).unwrap().is_a::<crate::types::Null>());
+		assert!(list_ref.iter().nth(3).unwrap().is_a::<crate::types::Null>());
+		assert_eq!(*list_ref.iter().nth(4).unwrap().downcast_ref::<Number>().unwrap(), Number::from(7));
+
+		assert!(List::qs_index_assign(&list, args!(-6, 0)).is_err());
+		assert!(List::qs_index_assign(&list, args!(1_000_000_000_000_000_000i64, 0)).is_err());
+		assert_eq!(list.downcast_ref::<List>().unwrap().len(), 5);
+	}
+
+	#[test]
+	fn contains() {
+		let list = List::from(vec![1.into(), "a".into(), true.into(), 1.into()]);
+
+		assert_eq!(list.qs_contains(args!(1)).unwrap(), true);
+		assert_eq!(list.qs_contains(args!("a")).unwrap(), true);
+		assert_eq!(list.qs_contains(args!(true)).unwrap(), true);
+		assert_eq!(list.qs_contains(args!("1")).unwrap(), false);
+		assert_eq!(list.qs_contains(args!(2)).unwrap(), false);
+		assert_eq!(numbers(&[]).qs_contains(args!(1)).unwrap(), false);
+	}
+
+	#[test]
+	fn index_of() {
+		let list = List::from(vec!["a".into(), 1.into(), "a".into(), 2.into()]);
+		let index_of = |args: Args| list.qs_index_of(args).unwrap()
+			.downcast_ref::<Number>()
+			.map(|num| *num);
+
+		assert_eq!(index_of(args!("a")), Some(Number::from(0)));
+		assert_eq!(index_of(args!(2)), Some(Number::from(3)));
+		assert_eq!(index_of(args!("a", 1)), Some(Number::from(2)));
+		assert_eq!(index_of(args!("a", 2)), Some(Number::from(2)));
+		assert_eq!(index_of(args!("a", -2)), Some(Number::from(2)));
+		assert_eq!(index_of(args!("a", 3)), None);
+		assert_eq!(index_of(args!("a", 10)), None);
+		assert_eq!(index_of(args!("2")), None);
+		assert_eq!(index_of(args!(true)), None);
+	}
+
+	#[test]
+	fn each() {
+		use std::sync::Mutex;
+		lazy_static::lazy_static! {
+			static ref SEEN: Mutex<Vec<Number>> = Mutex::new(Vec::new());
+		}
+
+		let record_until_three = Object::from(RustFn::new("record_until_three", |this, _| {
+			let num = *this.downcast_ref::<Number>().unwrap();
+
+			if num == Number::from(3) {
+				return Err(crate::error::ValueError::Messaged("three".to_string()).into());
+			}
+
+			SEEN.lock().unwrap().push(num);
+			Ok(Object::default())
+		}));
+
+		let list = Object::from(numbers(&[2, 1, 4]));
+		assert!(List::qs_each(&list, args!(record_until_three.clone())).unwrap().is_identical(&list));
+		assert_eq!(*SEEN.lock().unwrap(), vec![Number::from(2), Number::from(1), Number::from(4)]);
+
+		SEEN.lock().unwrap().clear();
+		let list = Object::from(numbers(&[1, 3, 2]));
+		assert!(List::qs_each(&list, args!(record_until_three)).is_err());
+		assert_eq!(*SEEN.lock().unwrap(), vec![Number::from(1)]);
+	}
+
+	#[test]
+	fn each_with_index() {
+		use std::sync::Mutex;
+		lazy_static::lazy_static! {
+			static ref SEEN: Mutex<Vec<(Number, Number)>> = Mutex::new(Vec::new());
+		}
+
+		let record = Object::from(RustFn::new("record", |this, args| {
+			let num = *this.downcast_ref::<Number>().unwrap();
+			let idx = *args.arg(0)?.downcast_ref::<Number>().unwrap();
+
+			SEEN.lock().unwrap().push((num, idx));
+			Ok(Object::default())
+		}));
+
+		let list = Object::from(numbers(&[7, 8]));
+		assert!(List::qs_each_with_index(&list, args!(record)).unwrap().is_identical(&list));
+		assert_eq!(
+			*SEEN.lock().unwrap(),
+			vec![(Number::from(7), Number::from(0)), (Number::from(8), Number::from(1))]
+		);
+	}
+
+	#[test]
+	fn unique() {
+		assert_eq!(to_numbers(numbers(&[1, 1, 1]).qs_unique(args!()).unwrap()), vec![Number::from(1)]);
+		assert_eq!(
+			to_numbers(numbers(&[3, 1, 2]).qs_unique(args!()).unwrap()),
+			vec![Number::from(3), Number::from(1), Number::from(2)]
+		);
+		assert_eq!(
+			to_numbers(numbers(&[1, 2, 1, 3, 2]).qs_unique(args!()).unwrap()),
+			vec![Number::from(1), Number::from(2), Number::from(3)]
+		);
+		assert!(numbers(&[]).qs_unique(args!()).unwrap().is_empty());
+
+		let mixed = List::from(vec![1.into(), "1".into(), 1.into()]).qs_unique(args!()).unwrap();
+		assert_eq!(mixed.len(), 2);
+	}
+
+	#[test]
+	fn dedup() {
+		let dedup = |nums: &[i32]| {
+			let list = Object::from(numbers(nums));
+			List::qs_dedup(&list, args!()).unwrap();
+			let list = list.downcast_ref::<List>().unwrap().clone();
+			to_numbers(list)
+		};
+
+		assert_eq!(dedup(&[1, 1, 1]), vec![Number::from(1)]);
+		assert_eq!(dedup(&[3, 1, 2]), vec![Number::from(3), Number::from(1), Number::from(2)]);
+		assert_eq!(
+			dedup(&[1, 2, 1, 3, 2]),
+			vec![Number::from(1), Number::from(2), Number::from(1), Number::from(3), Number::from(2)]
+		);
+		assert_eq!(dedup(&[1, 1, 2, 2, 1]), vec![Number::from(1), Number::from(2), Number::from(1)]);
+		assert_eq!(dedup(&[]), vec![]);
+	}
+
+	#[test]
+	fn reversed() {
+		assert!(numbers(&[]).qs_reversed(args!()).unwrap().is_empty());
+		assert_eq!(to_numbers(numbers(&[1]).qs_reversed(args!()).unwrap()), vec![Number::from(1)]);
+
+		let list = numbers(&[1, 2, 3]);
+		let reversed = list.qs_reversed(args!()).unwrap();
+
+		assert_eq!(to_numbers(reversed.clone()), vec![Number::from(3), Number::from(2), Number::from(1)]);
+		assert_eq!(to_numbers(list.clone()), vec![Number::from(1), Number::from(2), Number::from(3)]);
+
+		for (lhs, rhs) in list.iter().zip(reversed.iter().rev()) {
+			assert!(lhs.is_identical(rhs));
+		}
+	}
+
+	#[test]
+	fn reverse() {
+		let original = numbers(&[1, 2, 3]);
+		let list = Object::from(original.clone());
+
+		assert!(List::qs_reverse(&list, args!()).unwrap().is_identical(&list));
+
+		let reversed = list.downcast_ref::<List>().unwrap().clone();
+		assert_eq!(to_numbers(reversed.clone()), vec![Number::from(3), Number::from(2), Number::from(1)]);
+
+		for (lhs, rhs) in original.iter().zip(reversed.iter().rev()) {
+			assert!(lhs.is_identical(rhs));
+		}
+
+		let empty = Object::from(numbers(&[]));
+		List::qs_reverse(&empty, args!()).unwrap();
+		assert!(empty.downcast_ref::<List>().unwrap().is_empty());
+	}
+
+	#[test]
+	fn sum() {
+		assert_eq!(numbers(&[1, 2, 3]).qs_sum(args!()).unwrap(), Number::from(6));
+		assert_eq!(List::from(vec![1.into(), 2.5.into()]).qs_sum(args!()).unwrap(), Number::from(3.5));
+		assert_eq!(List::from(vec!["4".into(), true.into()]).qs_sum(args!()).unwrap(), Number::from(5));
+		assert_eq!(numbers(&[]).qs_sum(args!()).unwrap(), Number::ZERO);
+		assert!(List::from(vec!["x".into()]).qs_sum(args!()).is_err());
+	}
+
+	#[test]
+	fn product() {
+		assert_eq!(numbers(&[2, 3, 4]).qs_product(args!()).unwrap(), Number::from(24));
+		assert_eq!(List::from(vec!["3".into(), 0.5.into()]).qs_product(args!()).unwrap(), Number::from(1.5));
+		assert_eq!(numbers(&[]).qs_product(args!()).unwrap(), Number::ONE);
+	}
+
+	#[test]
+	fn min_and_max() {
+		let list = numbers(&[3, -1, 7, 2]);
+		assert_eq!(*list.qs_min(args!()).unwrap().downcast_ref::<Number>().unwrap(), Number::from(-1));
+		assert_eq!(*list.qs_max(args!()).unwrap().downcast_ref::<Number>().unwrap(), Number::from(7));
+
+		let list = List::from(vec!["b".into(), "c".into(), "a".into()]);
+		assert_eq!(list.qs_min(args!()).unwrap().downcast_ref::<Text>().unwrap().as_ref(), "a");
+		assert_eq!(list.qs_max(args!()).unwrap().downcast_ref::<Text>().unwrap().as_ref(), "c");
+
+		let list = numbers(&[1, 1]);
+		assert!(list.qs_min(args!()).unwrap().is_identical(list.iter().next().unwrap()));
+
+		assert!(numbers(&[]).qs_min(args!()).unwrap().is_a::<crate::types::Null>());
+		assert!(numbers(&[]).qs_max(args!()).unwrap().is_a::<crate::types::Null>());
+	}
+}
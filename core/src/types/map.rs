@@ -0,0 +1,469 @@
+use crate::{Object, Args};
+use crate::literals::{__INSPECT__, HASH};
+use crate::types::{Text, Number, List, Boolean};
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::RwLock;
+
+/// A Map in Quest.
+///
+/// Maps associate keys with values. Keys are looked up by their `hash` attribute, and then
+/// compared with their `==` attribute, so any object that has both can be used as a key.
+pub struct Map(RwLock<HashMap<Number, Vec<(Object, Object)>>>);
+
+impl Default for Map {
+	#[inline]
+	fn default() -> Self {
+		Map(RwLock::new(HashMap::new()))
+	}
+}
+
+impl Clone for Map {
+	fn clone(&self) -> Self {
+		Map(RwLock::new(self.0.read().expect("map poisoned").clone()))
+	}
+}
+
+impl Debug for Map {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let entries = self.entries();
+
+		if f.alternate() {
+			write!(f, "Map(")?;
+		}
+
+		f.debug_map().entries(entries.iter().map(|(k, v)| (k, v))).finish()?;
+
+		if f.alternate() {
+			write!(f, ")")?;
+		}
+
+		Ok(())
+	}
+}
+
+fn hash_of(key: &Object) -> crate::Result<Number> {
+	key.call_attr_lit(HASH, &[])?.downcast_call::<Number>()
+}
+
+/// Rust-centric map methods
+impl Map {
+	/// Create a new, empty map.
+	#[inline]
+	pub fn new() -> Self {
+		Map::default()
+	}
+
+	/// Get the amount of entries in the map.
+	pub fn len(&self) -> usize {
+		self.0.read().expect("map poisoned").values().map(Vec::len).sum()
+	}
+
+	/// Checks if the map is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Get a copy of all the entries, in no particular order.
+	pub fn entries(&self) -> Vec<(Object, Object)> {
+		self.0.read().expect("map poisoned").values().flatten().cloned().collect()
+	}
+
+	/// Find the position of `key` within the bucket for `hash`, along with the key it matched.
+	///
+	/// The bucket's keys are copied out first, so that their `==` attributes can access this map
+	/// without deadlocking. This means the bucket may have changed by the time the index is used,
+	/// so callers should make sure the matched key is still there with [`entry_at`].
+	///
+	/// [`entry_at`]: #method.entry_at
+	fn position(&self, hash: Number, key: &Object) -> crate::Result<Option<(usize, Object)>> {
+		let keys = self.0.read().expect("map poisoned")
+			.get(&hash)
+			.map(|bucket| bucket.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>())
+			.unwrap_or_default();
+
+		for (idx, other) in keys.into_iter().enumerate() {
+			if key.eq_obj(&other)? {
+				return Ok(Some((idx, other)));
+			}
+		}
+
+		Ok(None)
+	}
+
+	/// Checks whether `idx` in `bucket` still holds the key that [`position`] matched.
+	///
+	/// [`position`]: #method.position
+	fn entry_at(bucket: Option<&Vec<(Object, Object)>>, idx: usize, matched: &Object) -> bool {
+		bucket.and_then(|bucket| bucket.get(idx)).is_some_and(|(key, _)| key.is_identical(matched))
+	}
+
+	/// Get the value associated with `key`, if there is one.
+	pub fn get(&self, key: &Object) -> crate::Result<Option<Object>> {
+		let hash = hash_of(key)?;
+
+		loop {
+			let (idx, matched) =
+				match self.position(hash, key)? {
+					Some(found) => found,
+					None => return Ok(None)
+				};
+
+			let map = self.0.read().expect("map poisoned");
+			if Self::entry_at(map.get(&hash), idx, &matched) {
+				return Ok(Some(map[&hash][idx].1.clone()));
+			}
+		}
+	}
+
+	/// Checks to see if `key` is in the map.
+	#[inline]
+	pub fn has(&self, key: &Object) -> crate::Result<bool> {
+		let hash = hash_of(key)?;
+
+		Ok(self.position(hash, key)?.is_some())
+	}
+
+	/// Associate `value` with `key`, returning the previous value if there was one.
+	pub fn insert(&self, key: Object, value: Object) -> crate::Result<Option<Object>> {
+		let hash = hash_of(&key)?;
+
+		loop {
+			let found = self.position(hash, &key)?;
+			let mut map = self.0.write().expect("map poisoned");
+
+			match found {
+				Some((idx, matched)) if Self::entry_at(map.get(&hash), idx, &matched) => {
+					let old = &mut map.get_mut(&hash).expect("checked above")[idx].1;
+					return Ok(Some(std::mem::replace(old, value)));
+				},
+				// the bucket changed after we looked, so look again.
+				Some(_) => continue,
+				None => {
+					map.entry(hash).or_default().push((key, value));
+					return Ok(None);
+				}
+			}
+		}
+	}
+
+	/// Remove `key` from the map, returning its value if it was there.
+	pub fn delete(&self, key: &Object) -> crate::Result<Option<Object>> {
+		let hash = hash_of(key)?;
+
+		loop {
+			let (idx, matched) =
+				match self.position(hash, key)? {
+					Some(found) => found,
+					None => return Ok(None)
+				};
+
+			let mut map = self.0.write().expect("map poisoned");
+			if !Self::entry_at(map.get(&hash), idx, &matched) {
+				continue;
+			}
+
+			let bucket = map.get_mut(&hash).expect("checked above");
+			let (_, value) = bucket.remove(idx);
+
+			if bucket.is_empty() {
+				map.remove(&hash);
+			}
+
+			return Ok(Some(value));
+		}
+	}
+}
+
+/// Quest methods
+impl Map {
+	/// Converts this into a [`Text`], by calling `__inspect__` on each key and value.
+	///
+	/// For example, a map with the key `1` and value `"one"` becomes `{1: "one"}`.
+	pub fn qs_at_text(&self, _: Args) -> crate::Result<Text> {
+		let mut entries = vec![];
+
+		for (key, value) in self.entries() {
+			entries.push(format!("{}: {}",
+				key.call_attr_lit(__INSPECT__, &[])?.downcast_call::<Text>()?,
+				value.call_attr_lit(__INSPECT__, &[])?.downcast_call::<Text>()?));
+		}
+
+		Ok(format!("{{{}}}", entries.join(", ")).into())
+	}
+
+	/// Gets a representation of the map, which is the same as [`@text`](#method.qs_at_text).
+	#[inline]
+	#[allow(non_snake_case)]
+	pub fn qs___inspect__(&self, args: Args) -> crate::Result<Text> {
+		self.qs_at_text(args)
+	}
+
+	/// Returns a list of `[key, value]` pairs, in no particular order.
+	pub fn qs_at_list(&self, _: Args) -> Result<List, !> {
+		Ok(self.entries()
+			.into_iter()
+			.map(|(key, value)| List::from(vec![key, value]).into())
+			.collect::<Vec<Object>>()
+			.into())
+	}
+
+	/// A map is considered to be `false` when it is empty.
+	#[inline]
+	pub fn qs_at_bool(&self, _: Args) -> Result<Boolean, !> {
+		Ok((!self.is_empty()).into())
+	}
+
+	/// Returns the map itself.
+	#[inline]
+	pub fn qs_at_map(this: &Object, _: Args) -> Result<Object, !> {
+		Ok(this.clone())
+	}
+
+	/// Get the value associated with a key, or `null` if there isn't one.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The key to look up.
+	pub fn qs_index(&self, args: Args) -> crate::Result<Object> {
+		Ok(self.get(args.arg(0)?)?.unwrap_or_default())
+	}
+
+	/// Associates a value with a key, overwriting any previous value. Returns the map.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The key.
+	/// 2. (required) The value.
+	pub fn qs_index_assign(this: &Object, args: Args) -> crate::Result<Object> {
+		let key = args.arg(0)?;
+		let value = args.arg(1)?;
+
+		this.try_downcast_ref::<Self>()?.insert(key.clone(), value.clone())?;
+
+		Ok(this.clone())
+	}
+
+	/// Checks to see if the map contains the given key.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The key to look for.
+	#[inline]
+	pub fn qs_has(&self, args: Args) -> crate::Result<bool> {
+		self.has(args.arg(0)?)
+	}
+
+	/// Removes a key from the map, returning its value or `null` if it wasn't there.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The key to remove.
+	pub fn qs_delete(&self, args: Args) -> crate::Result<Object> {
+		Ok(self.delete(args.arg(0)?)?.unwrap_or_default())
+	}
+
+	/// Returns a list of all the keys, in no particular order.
+	pub fn qs_keys(&self, _: Args) -> Result<List, !> {
+		Ok(self.entries().into_iter().map(|(key, _)| key).collect::<Vec<_>>().into())
+	}
+
+	/// Returns a list of all the values, in no particular order.
+	pub fn qs_values(&self, _: Args) -> Result<List, !> {
+		Ok(self.entries().into_iter().map(|(_, value)| value).collect::<Vec<_>>().into())
+	}
+
+	/// Get the amount of entries in the map.
+	#[inline]
+	pub fn qs_len(&self, _: Args) -> Result<usize, !> {
+		Ok(self.len())
+	}
+}
+
+impl_object_type!{
+for Map [(parents super::Basic) (convert "@map")]:
+	"@text" => method Map::qs_at_text,
+	"__inspect__" => method Map::qs___inspect__,
+	"@list" => method Map::qs_at_list,
+	"@bool" => method Map::qs_at_bool,
+	"@map" => function Map::qs_at_map,
+
+	"[]" => method Map::qs_index,
+	"[]=" => function Map::qs_index_assign,
+	"has" => method Map::qs_has,
+	"delete" => method Map::qs_delete,
+	"keys" => method Map::qs_keys,
+	"values" => method Map::qs_values,
+	"len" => method Map::qs_len,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn text(t: &'static str) -> Object {
+		Text::new_static(t).into()
+	}
+
+	#[test]
+	fn number_keys() {
+		let map = Map::new();
+
+		assert!(map.insert(1.into(), text("one")).unwrap().is_none());
+		assert!(map.insert(2.5.into(), text("two and a half")).unwrap().is_none());
+
+		assert_eq!(map.len(), 2);
+		assert_eq!(map.get(&1.into()).unwrap().unwrap().downcast_call::<Text>().unwrap(), Text::from("one"));
+		assert_eq!(
+			map.get(&2.5.into()).unwrap().unwrap().downcast_call::<Text>().unwrap(),
+			Text::from("two and a half")
+		);
+		assert!(map.get(&3.into()).unwrap().is_none());
+		assert!(map.has(&1.into()).unwrap());
+		assert!(!map.has(&3.into()).unwrap());
+	}
+
+	#[test]
+	fn whole_float_keys() {
+		let map = Map::new();
+		map.insert(3.into(), text("three")).unwrap();
+
+		let three = Number::from(1.5) + Number::from(1.5);
+		assert!(map.has(&three.into()).unwrap());
+		assert!(map.has(&Number::float(3.0).into()).unwrap());
+		assert!(map.insert(Number::float(3.0).into(), text("THREE")).unwrap().is_some());
+		assert_eq!(map.len(), 1);
+	}
+
+	#[test]
+	fn signed_zero_keys() {
+		let map = Map::new();
+		map.insert(Number::float(0.0).into(), text("zero")).unwrap();
+
+		assert!(map.has(&Number::float(-0.0).into()).unwrap());
+		assert!(map.has(&0.into()).unwrap());
+		assert_eq!(
+			map.get(&Number::float(-0.0).into()).unwrap().unwrap().downcast_call::<Text>().unwrap(),
+			Text::from("zero")
+		);
+	}
+
+	#[test]
+	fn text_keys() {
+		let map = Map::new();
+
+		map.insert(text("a"), 1.into()).unwrap();
+		map.insert(text("b"), 2.into()).unwrap();
+
+		assert_eq!(map.qs_index(args!(Text::new_static("a"))).unwrap().downcast_call::<Number>().unwrap(), Number::from(1));
+		assert_eq!(map.qs_index(args!(Text::new_static("b"))).unwrap().downcast_call::<Number>().unwrap(), Number::from(2));
+		assert!(map.qs_index(args!(Text::new_static("c"))).unwrap().is_a::<crate::types::Null>());
+
+		// numbers and text are distinct keys
+		assert!(!map.has(&1.into()).unwrap());
+	}
+
+	#[test]
+	fn overwrite() {
+		let map = Object::from(Map::new());
+
+		Map::qs_index_assign(&map, args!(Text::new_static("a"), 1)).unwrap();
+		Map::qs_index_assign(&map, args!(Text::new_static("a"), 2)).unwrap();
+
+		let map = map.downcast_ref::<Map>().unwrap();
+		assert_eq!(map.len(), 1);
+		assert_eq!(map.get(&text("a")).unwrap().unwrap().downcast_call::<Number>().unwrap(), Number::from(2));
+	}
+
+	#[test]
+	fn delete() {
+		let map = Map::new();
+
+		map.insert(1.into(), text("one")).unwrap();
+		map.insert(text("two"), 2.into()).unwrap();
+
+		assert_eq!(map.qs_delete(args!(1)).unwrap().downcast_call::<Text>().unwrap(), Text::from("one"));
+		assert!(map.qs_delete(args!(1)).unwrap().is_a::<crate::types::Null>());
+		assert!(!map.has(&1.into()).unwrap());
+		assert_eq!(map.len(), 1);
+		assert!(map.has(&text("two")).unwrap());
+	}
+
+	#[test]
+	fn bucket_changes_during_lookup() {
+		use crate::types::{Basic, RustFn};
+
+		let key = |map: &Object| {
+			let key = Object::new(Basic);
+			key.set_attr_lit("hash", RustFn::new_boxed("hash", |_, _| Ok(0.into())));
+			map.downcast_ref::<Map>().unwrap().insert(key.clone(), 1.into()).unwrap();
+			key
+		};
+
+		let map = Object::from(Map::new());
+		let (first, second) = (key(&map), key(&map));
+
+		// a lookup key that's equal to `second`, but removes `first` while being compared to it,
+		// moving `second` to a different index in the bucket.
+		let lookup = Object::new(Basic);
+		lookup.set_attr_lit("hash", RustFn::new_boxed("hash", |_, _| Ok(0.into())));
+		lookup.set_attr_lit("==", RustFn::new_boxed("==", {
+			let (map, first, second) = (map.clone(), first.clone(), second.clone());
+			move |_, args| {
+				let rhs = args.arg(0)?;
+				if rhs.is_identical(&first) {
+					map.downcast_ref::<Map>().unwrap().delete(&first)?;
+				}
+				Ok(rhs.is_identical(&second).into())
+			}
+		}));
+
+		let map = map.downcast_ref::<Map>().unwrap();
+		assert!(map.insert(lookup.clone(), 2.into()).unwrap().is_some());
+		assert_eq!(map.len(), 1);
+		assert_eq!(map.get(&second).unwrap().unwrap().downcast_call::<Number>().unwrap(), Number::from(2));
+	}
+
+	#[test]
+	fn keys_values_and_list() {
+		let map = Map::new();
+
+		map.insert(1.into(), 10.into()).unwrap();
+		map.insert(2.into(), 20.into()).unwrap();
+
+		let mut keys = map.qs_keys(args!()).unwrap()
+			.iter()
+			.map(|key| *key.downcast_ref::<Number>().unwrap())
+			.collect::<Vec<_>>();
+		keys.sort();
+		assert_eq!(keys, vec![Number::from(1), Number::from(2)]);
+
+		let mut values = map.qs_values(args!()).unwrap()
+			.iter()
+			.map(|value| *value.downcast_ref::<Number>().unwrap())
+			.collect::<Vec<_>>();
+		values.sort();
+		assert_eq!(values, vec![Number::from(10), Number::from(20)]);
+
+		let pairs = map.qs_at_list(args!()).unwrap();
+		assert_eq!(pairs.len(), 2);
+		for pair in pairs.iter() {
+			let pair = pair.downcast_ref::<List>().unwrap();
+			let (key, value) = (pair.get(0), pair.get(1));
+			assert_eq!(
+				*value.downcast_ref::<Number>().unwrap(),
+				*key.downcast_ref::<Number>().unwrap() * Number::from(10)
+			);
+		}
+	}
+
+	#[test]
+	fn at_text() {
+		let map = Map::new();
+		assert_eq!(map.qs_at_text(args!()).unwrap(), Text::from("{}"));
+
+		map.insert(1.into(), text("one")).unwrap();
+		assert_eq!(map.qs_at_text(args!()).unwrap(), Text::from("{1: \"one\"}"));
+	}
+}
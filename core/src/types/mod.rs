@@ -36,6 +36,7 @@ pub mod number;
 pub mod text;
 
 pub mod list;
+pub mod map;
 
 pub use convert::Convertible;
 pub use comparable::Comparable;
@@ -51,4 +52,5 @@ pub use null::Null;
 pub use boolean::Boolean;
 pub use number::Number;
 pub use text::Text;
-pub use list::List;
\ No newline at end of file
+pub use list::List;
+pub use map::Map;
\ No newline at end of file
@@ -93,13 +93,19 @@ impl Null {
 		Ok(Text::from(*self))
 	}
 
+	/// Convert this to JSON, which is always `null`.
+	#[inline]
+	pub fn qs_to_json(&self, _: Args) -> Result<Text, !> {
+		Ok(Text::new_static("null"))
+	}
+
 	#[inline]
 	pub fn qs_call(&self, _: Args) -> Result<Null, !> {
 		Ok(*self)
 	}
 
 	#[inline]
-	pub fn qs_eql(&self, args: Args) -> Result<bool, crate::error::KeyError> {
+	pub fn qs_eql(&self, args: Args) -> Result<bool, crate::error::TypeError> {
 		let rhs = args.arg(0)?;
 		Ok(rhs.is_a::<Null>())
 	}
@@ -126,6 +132,7 @@ for Null {
 	"@bool" => method Null::qs_at_bool,
 	"@list" => method Null::qs_at_list,
 	"@num" => method Null::qs_at_num,
+	"to_json" => method Null::qs_to_json,
 	"()" => method Null::qs_call,
 	"==" => method Null::qs_eql,
 }
@@ -134,6 +141,11 @@ for Null {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn to_json() {
+		assert_eq!(Null.qs_to_json(args!()).unwrap().as_ref(), "null");
+	}
+
 	#[test]
 	fn at_bool() {
 		assert_eq!(Null.qs_at_bool(args!()).unwrap(), Boolean::FALSE);
@@ -149,6 +161,16 @@ mod tests {
 		assert_eq!(Null.qs_at_text(args!()).unwrap(), Text::new_static("null"));
 	}
 
+	#[test]
+	fn at_list() {
+		assert!(Null.qs_at_list(args!()).unwrap().is_empty());
+	}
+
+	#[test]
+	fn inspect() {
+		assert_eq!(Null.qs___inspect__(args!()).unwrap(), Text::new_static("null"));
+	}
+
 	dummy_object!(struct Dummy;);
 
 	#[test]
@@ -162,5 +184,10 @@ mod tests {
 	fn eql() {
 		assert_eq!(Null.qs_eql(args!(Dummy)).unwrap(), false);
 		assert_eq!(Null.qs_eql(args!(Null)).unwrap(), true);
+		assert_eq!(Null.qs_eql(args!(false)).unwrap(), false);
+		assert_eq!(Null.qs_eql(args!(0)).unwrap(), false);
+		assert_eq!(Null.qs_eql(args!("null")).unwrap(), false);
+		assert_eq!(Null.qs_eql(args!(List::from(vec![]))).unwrap(), false);
+		assert!(Null.qs_eql(args!()).is_err());
 	}
 }
\ No newline at end of file
@@ -2,8 +2,10 @@ use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::cmp::Ordering;
 use crate::{Object, Args};
-use crate::types::{Text, Boolean};
+use crate::types::{Text, Boolean, List};
+use crate::error::ValueError;
 use std::hash::{Hash, Hasher};
+use std::cell::Cell;
 
 pub type IntegerType = i64;
 pub type FloatType = f64;
@@ -19,6 +21,10 @@ enum Inner {
 
 impl Eq for Number {}
 
+thread_local!(
+	static DISPLAY_PRECISION: Cell<Option<usize>> = const { Cell::new(None) };
+);
+
 impl PartialEq for Number {
 	fn eq(&self, rhs: &Number) -> bool {
 		use Inner::*;
@@ -34,9 +40,11 @@ impl PartialEq for Number {
 impl Hash for Number {
 	#[inline]
 	fn hash<H: Hasher>(&self, h: &mut H) {
-		// in the future, we should probably change how floats hash
+		// equal numbers must hash the same, so whole floats (including `-0.0`) hash as integers.
 		match self.0 {
 			Inner::Integer(i) => i.hash(h),
+			#[allow(clippy::float_cmp)]
+			Inner::Float(f) if f as IntegerType as FloatType == f => (f as IntegerType).hash(h),
 			Inner::Float(f) => f.to_bits().hash(h)
 		}
 	}
@@ -76,7 +84,9 @@ impl Display for Number {
 pub enum FromStrError {
 	BadInteger(std::num::ParseIntError),
 	BadFloat(std::num::ParseFloatError),
-	BadRadix(u32)
+	BadRadix(u32),
+	/// An underscore at the given byte index wasn't between two digits.
+	MisplacedUnderscore(usize)
 }
 
 impl Display for FromStrError {
@@ -84,7 +94,9 @@ impl Display for FromStrError {
 		match self {
 			FromStrError::BadInteger(err) => Display::fmt(err, f),
 			FromStrError::BadFloat(err) => Display::fmt(err, f),
-			FromStrError::BadRadix(radix) => write!(f, "bad radix: {}", radix)
+			FromStrError::BadRadix(radix) => write!(f, "bad radix: {}", radix),
+			FromStrError::MisplacedUnderscore(idx) =>
+				write!(f, "misplaced underscore at index {}: underscores must be between digits", idx)
 		}
 	}
 }
@@ -94,7 +106,7 @@ impl std::error::Error for FromStrError {
 		match self {
 			FromStrError::BadInteger(ref err) => Some(err),
 			FromStrError::BadFloat(ref err) => Some(err),
-			FromStrError::BadRadix(_) => None
+			FromStrError::BadRadix(_) | FromStrError::MisplacedUnderscore(_) => None
 		}
 	}
 }
@@ -107,6 +119,38 @@ impl Number {
 	pub const  NAN: Self = Number(Inner::Float(f64::NAN));
 	pub const  INF: Self = Number(Inner::Float(f64::INFINITY));
 
+	/// Create a float, even if `f` is whole.
+	///
+	/// Unlike [`Number::from`], which stores whole floats as integers, this always keeps the
+	/// float representation.
+	#[inline]
+	pub const fn float(f: FloatType) -> Self {
+		Number(Inner::Float(f))
+	}
+
+	/// Create an integer.
+	#[inline]
+	pub const fn integer(n: IntegerType) -> Self {
+		Number(Inner::Integer(n))
+	}
+
+	/// Whether this is stored as a float.
+	#[inline]
+	pub fn is_float(self) -> bool {
+		matches!(self.0, Inner::Float(_))
+	}
+
+	/// Whether this is stored as an integer.
+	#[inline]
+	pub fn is_integer(self) -> bool {
+		matches!(self.0, Inner::Integer(_))
+	}
+
+	/// Whether `self` is `NaN`.
+	#[inline]
+	pub fn is_nan(self) -> bool {
+		matches!(self.0, Inner::Float(f) if f.is_nan())
+	}
 
 	#[inline]
 	pub fn ceil(self) -> IntegerType {
@@ -124,6 +168,84 @@ impl Number {
 		}
 	}
 
+	/// Truncates `self` toward zero, discarding any fractional part.
+	///
+	/// Unlike [`floor`](Number::floor), negative numbers move _up_ (`-2.5` becomes `-2`, not
+	/// `-3`), and unlike rounding, the fractional part is never looked at (`2.9` becomes `2`).
+	/// This never fails: values outside the integer range saturate, and `NaN` becomes `0`.
+	#[inline]
+	pub fn trunc(self) -> IntegerType {
+		match self.0 {
+			Inner::Integer(i) => i,
+			Inner::Float(f) => f.trunc() as _
+		}
+	}
+
+	/// The number of significant digits floats are rounded to when converted to [`Text`] via
+	/// `@text`, or `None` (the default) if they aren't rounded.
+	#[inline]
+	pub fn display_precision() -> Option<usize> {
+		DISPLAY_PRECISION.with(Cell::get)
+	}
+
+	/// Set the number of significant digits `@text` rounds floats to for the current thread.
+	///
+	/// This doesn't affect `Debug`, `Display` or `__inspect__`.
+	///
+	/// # Panics
+	/// If `precision` is `Some(0)`.
+	#[inline]
+	pub fn set_display_precision(precision: Option<usize>) {
+		assert_ne!(precision, Some(0), "display precision must be at least one digit");
+
+		DISPLAY_PRECISION.with(|p| p.set(precision))
+	}
+
+	/// Format this like `Display` does, except floats are rounded to the
+	/// [`display_precision`](Number::display_precision), if one is set.
+	pub fn to_string_with_precision(self) -> String {
+		match (self.0, Number::display_precision()) {
+			(Inner::Float(f), Some(precision)) if f.is_finite() =>
+				// round-tripping through scientific notation rounds to `precision` significant
+				// digits, and then printing the parsed float drops any trailing zeros.
+				format!("{:.*e}", precision - 1, f).parse::<FloatType>()
+					.expect("scientific notation is always a valid float")
+					.to_string(),
+			_ => self.to_string()
+		}
+	}
+
+	/// Format this as JSON.
+	///
+	/// Integers are written without a decimal point. JSON has no way to represent `NaN` or the
+	/// infinities, so they're a [`ValueError`].
+	pub fn to_json(self) -> Result<String, ValueError> {
+		match self.0 {
+			Inner::Integer(i) => Ok(i.to_string()),
+			Inner::Float(f) if f.is_finite() => Ok(f.to_string()),
+			Inner::Float(f) =>
+				Err(ValueError::Messaged(format!("{} can't be represented in JSON", f)))
+		}
+	}
+
+	/// Format this in scientific notation (eg `1.23e4`), optionally with `precision` digits after
+	/// the decimal point.
+	///
+	/// Integers are converted to floats first, so very large ones may lose precision. Without a
+	/// `precision`, as many digits as are needed to represent the float exactly are used.
+	pub fn to_scientific(self, precision: Option<usize>) -> String {
+		let float =
+			match self.0 {
+				Inner::Integer(i) => i as FloatType,
+				Inner::Float(f) => f
+			};
+
+		match precision {
+			Some(precision) => format!("{:.*e}", precision, float),
+			None => format!("{:e}", float)
+		}
+	}
+
 	pub fn from_str_radix(inp: &str, radix: u32) -> Result<Self, FromStrError> {
 		if radix < 2 || radix > 36 {
 			return Err(FromStrError::BadRadix(radix))
@@ -146,6 +268,17 @@ impl TryFrom<&'_ str> for Number {
 		if inp.find('_') != None {
 			// we don't want to have to convert everything to a string in case a `_` doesn't exist, so
 			// we check for `_`'s existance first.
+			let bytes = inp.as_bytes();
+			for (idx, _) in inp.match_indices('_') {
+				let is_digit_at = |idx: Option<usize>| idx
+					.and_then(|idx| bytes.get(idx))
+					.is_some_and(u8::is_ascii_digit);
+
+				if !is_digit_at(idx.checked_sub(1)) || !is_digit_at(Some(idx + 1)) {
+					return Err(FromStrError::MisplacedUnderscore(idx));
+				}
+			}
+
 			let mut inp = inp.to_string();
 
 			while let Some(idx) = inp.rfind('_') {
@@ -191,14 +324,46 @@ impl Number {
 		let this = IntegerType::try_from(*self).map_err(ToStringRadixError::NotAnInteger)?;
 
 		match radix {
-         2 => Ok(format!("{:b}", this)),
-         8 => Ok(format!("{:o}", this)),
-         16 => Ok(format!("{:x}", this)),
-         10 => Ok(format!("{}", this)),
-         radix @ 0 | radix @ 1 => Err(ToStringRadixError::InvalidRadix(radix)),
-         other => todo!("unsupported radix {}", other),
+			2 => Ok(format!("{:b}", this)),
+			8 => Ok(format!("{:o}", this)),
+			16 => Ok(format!("{:x}", this)),
+			10 => Ok(format!("{}", this)),
+			other => {
+				let digits = self.digits(other)?.into_iter()
+					.map(|digit| std::char::from_digit(digit, other).expect("digit out of range"));
+
+				Ok(if this < 0 { "-" } else { "" }.chars().chain(digits).collect())
+			}
 		}
 	}
+
+	/// Get the digits of this number's magnitude in the given `radix`, most significant first.
+	///
+	/// The sign is ignored, and zero is a single `0` digit. Only radices between 2 and 36 are
+	/// supported.
+	pub fn digits(&self, radix: u32) -> Result<Vec<u32>, ToStringRadixError> {
+		let this = IntegerType::try_from(*self).map_err(ToStringRadixError::NotAnInteger)?;
+
+		if !(2..=36).contains(&radix) {
+			return Err(ToStringRadixError::InvalidRadix(radix));
+		}
+
+		let radix = u64::from(radix);
+		let mut magnitude = this.unsigned_abs();
+		let mut digits = vec![];
+
+		loop {
+			digits.push((magnitude % radix) as u32);
+			magnitude /= radix;
+
+			if magnitude == 0 {
+				break;
+			}
+		}
+
+		digits.reverse();
+		Ok(digits)
+	}
 }
 
 impl PartialOrd for Number {
@@ -253,10 +418,9 @@ impl_try_from_number!(u8 u16 u32 u64 u128 i8 i16 i32 i64 i128);
 
 impl From<FloatType> for Number {
 	fn from(f: FloatType) -> Number {
+		// whole floats too large to be an integer stay as floats.
 		#[allow(clippy::float_cmp)]
-		if f.is_normal() && f.floor() == f {
-			assert!(f.is_normal() && (f as IntegerType as FloatType) == f, "bad f: {}", f);
-
+		if f.is_normal() && f.floor() == f && (f as IntegerType as FloatType) == f {
 			Number(Inner::Integer(f as _))
 		} else {
 			Number(Inner::Float(f))
@@ -394,6 +558,7 @@ macro_rules! impl_bitwise_ops {
 	};
 }
 
+// note that `<<` and `>>` don't check the shift amount; use `checked_shl`/`checked_shr` for that.
 impl_bitwise_ops! {
 	BitAnd bitand bitand_assign
 	BitOr bitor bitor_assign
@@ -423,12 +588,65 @@ impl std::ops::Not for Number {
 }
 
 impl Number {
+	/// Returns the absolute value of `self`.
+	///
+	/// Like [`signum`](Number::signum) and [`copysign`](Number::copysign), the result is an
+	/// integer if `self` is, and a float otherwise. The one exception is the smallest integer,
+	/// whose absolute value doesn't fit in an integer and so is returned as a float.
 	#[inline]
 	pub fn abs(self) -> Number {
 		match self.0 {
-			Inner::Integer(i) => Number::from(i.abs()),
-			Inner::Float(f) => Number::from(f.abs())
+			Inner::Integer(i) => i.checked_abs()
+				.map_or_else(|| Number::float(-(i as FloatType)), Number::integer),
+			Inner::Float(f) => Number::float(f.abs())
+		}
+	}
+
+	/// Returns `-1`, `0`, or `1` depending on the sign of `self`, as an integer if `self` is one
+	/// and a float otherwise.
+	///
+	/// Floats follow IEEE semantics: `0.0` and `-0.0` are returned as-is (keeping their sign),
+	/// and `NaN` stays `NaN`.
+	#[inline]
+	pub fn signum(self) -> Number {
+		match self.0 {
+			Inner::Integer(i) => Number::integer(i.signum()),
+			Inner::Float(f) if f == 0.0 || f.is_nan() => Number::float(f),
+			Inner::Float(f) => Number::float(f.signum())
+		}
+	}
+
+	/// Returns `self` with the magnitude unchanged but the sign of `sign`.
+	///
+	/// Like [`f64::copysign`], a `sign` of `-0.0` counts as negative. Whatever `sign` is, the
+	/// result is an integer if `self` is (except as described in [`abs`](Number::abs)), and a
+	/// float otherwise.
+	#[inline]
+	pub fn copysign(self, sign: Number) -> Number {
+		let negative =
+			match sign.0 {
+				Inner::Integer(s) => s < 0,
+				Inner::Float(s) => s.is_sign_negative()
+			};
+
+		match self.0 {
+			Inner::Integer(i) if negative => Number::integer(if i > 0 { -i } else { i }),
+			Inner::Integer(_) => self.abs(),
+			Inner::Float(f) => Number::float(f.copysign(if negative { -1.0 } else { 1.0 }))
+		}
+	}
+
+	/// Checks whether `low <= self <= high`, or `low <= self < high` if `exclusive` is set.
+	///
+	/// If `low` is greater than `high`, no number is in range and this returns `false`. Nothing is
+	/// between anything and [`NAN`](#associatedconstant.NAN), so that's `false` too.
+	#[inline]
+	pub fn between(self, low: Number, high: Number, exclusive: bool) -> bool {
+		if self.is_nan() || low.is_nan() || high.is_nan() {
+			return false;
 		}
+
+		low <= self && if exclusive { self < high } else { self <= high }
 	}
 
 	#[inline]
@@ -437,6 +655,67 @@ impl Number {
 		self
 	}
 
+	/// Divide `self` by `rhs`, returning an error instead of [`NAN`](#associatedconstant.NAN) or
+	/// an infinity if `rhs` is zero.
+	pub fn checked_div(self, rhs: Number) -> Result<Number, ValueError> {
+		if rhs == Number::ZERO {
+			Err(ValueError::DivisionByZero(self))
+		} else {
+			Ok(self / rhs)
+		}
+	}
+
+	/// Shift `self` left by `rhs` bits, returning an error if `rhs` isn't in `0..64`.
+	///
+	/// Unlike `<<`, which doesn't check the shift amount, this never overflows the shift.
+	pub fn checked_shl(self, rhs: Number) -> Result<Number, ValueError> {
+		let (lhs, rhs) = Self::shift_operands(self, rhs)?;
+		Ok(Number::from(lhs << rhs))
+	}
+
+	/// Shift `self` right by `rhs` bits, returning an error if `rhs` isn't in `0..64`.
+	///
+	/// Unlike `>>`, which doesn't check the shift amount, this never overflows the shift.
+	pub fn checked_shr(self, rhs: Number) -> Result<Number, ValueError> {
+		let (lhs, rhs) = Self::shift_operands(self, rhs)?;
+		Ok(Number::from(lhs >> rhs))
+	}
+
+	fn shift_operands(lhs: Number, rhs: Number) -> Result<(IntegerType, u32), ValueError> {
+		let lhs = IntegerType::try_from(lhs).map_err(|err| ValueError::Messaged(err.to_string()))?;
+		let rhs = IntegerType::try_from(rhs).map_err(|err| ValueError::Messaged(err.to_string()))?;
+
+		if 0 <= rhs && rhs < IntegerType::BITS as IntegerType {
+			Ok((lhs, rhs as u32))
+		} else {
+			Err(ValueError::Messaged(
+				format!("shift amount must be between 0 and {}, not {}", IntegerType::BITS - 1, rhs)))
+		}
+	}
+
+	/// The remainder of `self / rhs`, taking the sign of `rhs` (ie floored modulo).
+	///
+	/// This is unlike `%`, where the remainder has the sign of `self`: `-1 % 3` is `-1`, but
+	/// `(-1).modulo(3)` is `2`. Like division, a zero `rhs` yields [`NAN`](#associatedconstant.NAN).
+	pub fn modulo(self, rhs: Number) -> Number {
+		use Inner::*;
+
+		fn floored(rem: FloatType, rhs: FloatType) -> FloatType {
+			if rem != 0.0 && (rem < 0.0) != (rhs < 0.0) { rem + rhs } else { rem }
+		}
+
+		match (self.0, rhs.0) {
+			(_, Integer(0)) => Number::NAN,
+			(Integer(l), Integer(r)) => {
+				let rem = l.wrapping_rem(r);
+				Number::from(if rem != 0 && (rem < 0) != (r < 0) { rem + r } else { rem })
+			},
+			(Integer(l), Float(r)) => Number::from(floored(l as FloatType % r, r)),
+			(Float(l), Integer(r)) => Number::from(floored(l % r as FloatType, r as FloatType)),
+			(Float(l), Float(r)) => Number::from(floored(l % r, r)),
+		}
+	}
+
 	pub fn pow_assign(&mut self, rhs: Self) {
 		use Inner::*;
 		match (self.0, rhs.0) {
@@ -487,10 +766,51 @@ impl Number {
 				.map_err(|err| err.to_string().into())
 				.map(Text::from)
 		} else {
-			Ok(Text::from(*self))
+			Ok(Text::from(self.to_string_with_precision()))
 		}
 	}
 
+	/// Set how many significant digits floats are rounded to by `@text`. Without an argument (or
+	/// with `null`), floats are no longer rounded.
+	///
+	/// Returns the previous precision, or `null` if there wasn't one.
+	pub fn qs_set_display_precision(_: &Object, args: Args) -> crate::Result<Object> {
+		let precision =
+			match args.arg(0) {
+				Ok(precision) if !precision.is_a::<crate::types::Null>() => {
+					let precision = args.arg_as::<Number>(0, "set_display_precision")?.floor();
+
+					match usize::try_from(precision) {
+						Ok(precision) if precision != 0 => Some(precision),
+						_ => return Err(ValueError::Messaged(
+							format!("display precision must be positive, not {}", precision)
+						).into())
+					}
+				},
+				_ => None
+			};
+
+		let old = Number::display_precision();
+		Number::set_display_precision(precision);
+
+		Ok(old.map_or_else(Object::default, |old| Number::from(old as IntegerType).into()))
+	}
+
+	/// Get a list of this number's digits, in an optional radix (which defaults to 10).
+	pub fn qs_at_list(&self, args: Args) -> crate::Result<List> {
+		let radix =
+			match args.arg(0) {
+				Ok(_) => args.arg_as::<Number>(0, "@list")?.floor(),
+				Err(_) => 10
+			};
+
+		let radix = u32::try_from(radix).unwrap_or(0);
+
+		self.digits(radix)
+			.map(|digits| digits.into_iter().map(Object::from).collect::<Vec<_>>().into())
+			.map_err(|err| ValueError::Messaged(err.to_string()).into())
+	}
+
 	#[inline]
 	pub fn qs_at_bool(&self, _: Args) -> Result<Boolean, !> {
 		Ok(Boolean::from(*self))
@@ -503,13 +823,13 @@ impl Number {
 
 	#[inline]
 	pub fn qs_add(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "+")?;
 
 		Ok(*self + rhs)
 	}
 
 	pub fn qs_add_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "+=")?;
 
 		*this.try_downcast_mut::<Self>()? += rhs;
 
@@ -518,13 +838,13 @@ impl Number {
 
 	#[inline]
 	pub fn qs_sub(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "-")?;
 
 		Ok(*self - rhs)
 	}
 
 	pub fn qs_sub_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "-=")?;
 
 		*this.try_downcast_mut::<Self>()? -= rhs;
 
@@ -533,13 +853,13 @@ impl Number {
 
 	#[inline]
 	pub fn qs_mul(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "*")?;
 
 		Ok(*self * rhs)
 	}
 
 	pub fn qs_mul_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "*=")?;
 
 		*this.try_downcast_mut::<Self>()? *= rhs;
 
@@ -548,13 +868,20 @@ impl Number {
 
 	#[inline]
 	pub fn qs_div(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "/")?;
 
 		Ok(*self / rhs)
 	}
 
+	#[inline]
+	pub fn qs_checked_div(&self, args: Args) -> crate::Result<Self> {
+		let rhs = args.arg_as::<Self>(0, "checked/")?;
+
+		self.checked_div(rhs).map_err(Into::into)
+	}
+
 	pub fn qs_div_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "/=")?;
 
 		*this.try_downcast_mut::<Self>()? /= rhs;
 
@@ -563,28 +890,35 @@ impl Number {
 
 	#[inline]
 	pub fn qs_mod(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "%")?;
 
 		Ok(*self % rhs)
 	}
 
 	pub fn qs_mod_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "%=")?;
 
 		*this.try_downcast_mut::<Self>()? %= rhs;
 
 		Ok(this.clone())
 	}
 
+	#[inline]
+	pub fn qs_modulo(&self, args: Args) -> crate::Result<Self> {
+		let rhs = args.arg_as::<Self>(0, "mod")?;
+
+		Ok(self.modulo(rhs))
+	}
+
 	#[inline]
 	pub fn qs_pow(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "**")?;
 
 		Ok(self.pow(rhs))
 	}
 
 	pub fn qs_pow_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Self>()?;
+		let rhs = args.arg_as::<Self>(0, "**=")?;
 
 		let mut this_mut = this.try_downcast_mut::<Self>()?;
 		*this_mut = this_mut.pow(rhs);
@@ -594,13 +928,13 @@ impl Number {
 
 	#[inline]
 	pub fn qs_bitand(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "&")?;
 
 		(*self & rhs).map_err(|err| err.to_string().into())
 	}
 
 	pub fn qs_bitand_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "&=")?;
 
 		this.try_downcast_mut::<Number>()?.bitand_assign(rhs).map_err(|err| err.to_string())?;
 
@@ -609,13 +943,13 @@ impl Number {
 
 	#[inline]
 	pub fn qs_bitor(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "|")?;
 
 		(*self | rhs).map_err(|err| err.to_string().into())
 	}
 
 	pub fn qs_bitor_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "|=")?;
 
 		this.try_downcast_mut::<Number>()?.bitor_assign(rhs).map_err(|err| err.to_string())?;
 
@@ -624,28 +958,42 @@ impl Number {
 
 	#[inline]
 	pub fn qs_bitxor(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "^")?;
 
 		(*self ^ rhs).map_err(|err| err.to_string().into())
 	}
 
 	pub fn qs_bitxor_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "^=")?;
 
 		this.try_downcast_mut::<Number>()?.bitxor_assign(rhs).map_err(|err| err.to_string())?;
 
 		Ok(this.clone())
 	}
 
+	#[inline]
+	pub fn qs_checked_shl(&self, args: Args) -> crate::Result<Self> {
+		let rhs = args.arg_as::<Number>(0, "checked<<")?;
+
+		self.checked_shl(rhs).map_err(Into::into)
+	}
+
+	#[inline]
+	pub fn qs_checked_shr(&self, args: Args) -> crate::Result<Self> {
+		let rhs = args.arg_as::<Number>(0, "checked>>")?;
+
+		self.checked_shr(rhs).map_err(Into::into)
+	}
+
 	#[inline]
 	pub fn qs_shl(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "<<")?;
 
 		(*self << rhs).map_err(|err| err.to_string().into())
 	}
 
 	pub fn qs_shl_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "<<=")?;
 
 		this.try_downcast_mut::<Number>()?.shl_assign(rhs).map_err(|err| err.to_string())?;
 
@@ -654,13 +1002,13 @@ impl Number {
 
 	#[inline]
 	pub fn qs_shr(&self, args: Args) -> crate::Result<Self> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, ">>")?;
 
 		(*self >> rhs).map_err(|err| err.to_string().into())
 	}
 
 	pub fn qs_shr_assign(this: &Object, args: Args) -> crate::Result<Object> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, ">>=")?;
 
 		this.try_downcast_mut::<Number>()?.shr_assign(rhs).map_err(|err| err.to_string())?;
 
@@ -683,24 +1031,100 @@ impl Number {
 		Ok((!*self).map_err(|x| x.to_string())?)
 	}
 
+	/// Counts how many bits are set in the number's two's complement representation.
+	#[inline]
+	pub fn qs_count_ones(&self, _: Args) -> crate::Result<Self> {
+		let int = IntegerType::try_from(*self).map_err(|err| err.to_string())?;
+
+		Ok(Number::from(int.count_ones()))
+	}
+
+	/// Counts the zero bits above the most significant set bit, out of 64.
+	#[inline]
+	pub fn qs_leading_zeros(&self, _: Args) -> crate::Result<Self> {
+		let int = IntegerType::try_from(*self).map_err(|err| err.to_string())?;
+
+		Ok(Number::from(int.leading_zeros()))
+	}
+
+	/// Counts the zero bits below the least significant set bit; zero has 64.
+	#[inline]
+	pub fn qs_trailing_zeros(&self, _: Args) -> crate::Result<Self> {
+		let int = IntegerType::try_from(*self).map_err(|err| err.to_string())?;
+
+		Ok(Number::from(int.trailing_zeros()))
+	}
+
+	/// Checks whether the `n`th bit (counting from the least significant, starting at zero) is set.
+	pub fn qs_bit(&self, args: Args) -> crate::Result<bool> {
+		let int = IntegerType::try_from(*self).map_err(|err| err.to_string())?;
+		let idx = IntegerType::try_from(args.arg_as::<Number>(0, "bit")?)
+			.map_err(|err| err.to_string())?;
+
+		if 0 <= idx && idx < IntegerType::BITS as IntegerType {
+			Ok(int & (1 << idx) != 0)
+		} else {
+			Err(ValueError::Messaged(
+				format!("bit index must be between 0 and {}, not {}", IntegerType::BITS - 1, idx)).into())
+		}
+	}
+
 	#[inline]
 	pub fn qs_abs(&self, _: Args) -> Result<Self, !> {
 		Ok(self.abs())
 	}
 
 	#[inline]
-	pub fn qs_eql(&self, args: Args) -> Result<bool, crate::error::KeyError> {
+	pub fn qs_signum(&self, _: Args) -> Result<Self, !> {
+		Ok(self.signum())
+	}
+
+	#[inline]
+	pub fn qs_copysign(&self, args: Args) -> crate::Result<Self> {
+		let sign = args.arg_as::<Number>(0, "copysign")?;
+
+		Ok(self.copysign(sign))
+	}
+
+	#[inline]
+	pub fn qs_eql(&self, args: Args) -> Result<bool, crate::error::TypeError> {
 		let rhs = args.arg(0)?.downcast_ref::<Number>();
 		Ok(rhs.map(|rhs| *self == *rhs).unwrap_or(false))
 	}
 
+	/// The hash for this, which is the same for equal integers and floats (eg `3` and `3.0`).
 	#[inline]
+	pub fn qs_hash(&self, _: Args) -> Result<Number, !> {
+		Ok(crate::utils::stable_hash(self))
+	}
+
+	/// Compares this with another number.
+	///
+	/// [`NAN`](#associatedconstant.NAN) isn't ordered with respect to anything, so comparing with
+	/// it is a [`ValueError`].
 	pub fn qs_cmp(&self, args: Args) -> crate::Result<std::cmp::Ordering> {
-		let rhs = args.arg(0)?.downcast_call::<Number>()?;
+		let rhs = args.arg_as::<Number>(0, "<=>")?;
+
+		if self.is_nan() || rhs.is_nan() {
+			return Err(ValueError::Messaged(format!("can't compare {} and {}", self, rhs)).into());
+		}
 
 		Ok(self.cmp(&rhs))
 	}
 
+	pub fn qs_between(&self, args: Args) -> crate::Result<Boolean> {
+		let low = args.arg_as::<Number>(0, "between")?;
+		let high = args.arg_as::<Number>(1, "between")?;
+		let exclusive = args.arg(2)
+			.ok()
+			.map(|x| x.downcast_call::<Boolean>())
+			.transpose()?
+			.map(bool::from)
+			.unwrap_or(false);
+
+		Ok(Boolean::from(self.between(low, high, exclusive)))
+	}
+
 	#[inline]
 	pub fn qs_floor(&self, _: Args) -> Result<Self, !> {
 		Ok(Number::from(self.floor()))
@@ -711,6 +1135,53 @@ impl Number {
 		Ok(Number::from(self.ceil()))
 	}
 
+	/// Truncate toward zero, always returning an integer.
+	///
+	/// This is the infallible counterpart to converting to an integer directly, which
+	/// fails for non-integral numbers.
+	#[inline]
+	pub fn qs_to_int(&self, _: Args) -> Result<Self, !> {
+		Ok(Number::from(self.trunc()))
+	}
+
+	/// Convert this to a float, which stays a float even if it's whole.
+	#[inline]
+	pub fn qs_to_float(&self, _: Args) -> Result<Self, !> {
+		match self.0 {
+			Inner::Integer(i) => Ok(Number::float(i as _)),
+			Inner::Float(_) => Ok(*self)
+		}
+	}
+
+	/// Truncate toward zero; see [`Number::trunc`] for how this differs from `floor` and `round`.
+	#[inline]
+	pub fn qs_trunc(&self, _: Args) -> Result<Self, !> {
+		Ok(Number::from(self.trunc()))
+	}
+
+	/// Convert this to JSON; see [`Number::to_json`].
+	#[inline]
+	pub fn qs_to_json(&self, _: Args) -> Result<Text, ValueError> {
+		self.to_json().map(Text::from)
+	}
+
+	/// Convert this to a [`Text`] in scientific notation, with an optional precision.
+	///
+	/// See [`Number::to_scientific`] for details.
+	pub fn qs_to_scientific(&self, args: Args) -> crate::Result<Text> {
+		let precision =
+			match args.arg(0) {
+				Ok(_) => {
+					let precision = args.arg_as::<Number>(0, "to_scientific")?.floor();
+					Some(usize::try_from(precision).map_err(|_| ValueError::Messaged(
+						format!("precision must not be negative, not {}", precision)))?)
+				},
+				Err(_) => None
+			};
+
+		Ok(self.to_scientific(precision).into())
+	}
+
 	#[inline]
 	pub fn qs_round(&self, _: Args) -> Result<Self, !> {
 		unimplemented!("round");
@@ -756,11 +1227,14 @@ impl_object_type!{
 	"__inspect__" => method Number::qs___inspect__,
 	"@num" => function Number::qs_at_num,
 	"@bool" => method Number::qs_at_bool,
+	"@list" => method Number::qs_at_list,
 
 	"+"  => method Number::qs_add,    "+="  => function Number::qs_add_assign,
 	"-"  => method Number::qs_sub,    "-="  => function Number::qs_sub_assign,
 	"*"  => method Number::qs_mul,    "*="  => function Number::qs_mul_assign,
 	"/"  => method Number::qs_div,    "/="  => function Number::qs_div_assign,
+	"checked/" => method Number::qs_checked_div,
+	"mod" => method Number::qs_modulo,
 	"%"  => method Number::qs_mod,    "%="  => function Number::qs_mod_assign,
 	"**" => method Number::qs_pow,    "**=" => function Number::qs_pow_assign,
 	"&"  => method Number::qs_bitand, "&="  => function Number::qs_bitand_assign,
@@ -768,18 +1242,34 @@ impl_object_type!{
 	"^"  => method Number::qs_bitxor, "^="  => function Number::qs_bitxor_assign,
 	"<<" => method Number::qs_shl,    "<<=" => function Number::qs_shl_assign,
 	">>" => method Number::qs_shr,    ">>=" => function Number::qs_shr_assign,
+	"checked<<" => method Number::qs_checked_shl,
+	"checked>>" => method Number::qs_checked_shr,
 
 	"-@"  => method Number::qs_neg,
 	"+@"  => method Number::qs_pos,
 	"~"   => method Number::qs_bitnot,
+	"count_ones" => method Number::qs_count_ones,
+	"leading_zeros" => method Number::qs_leading_zeros,
+	"trailing_zeros" => method Number::qs_trailing_zeros,
+	"bit" => method Number::qs_bit,
 	"abs" => method Number::qs_abs,
+	"signum" => method Number::qs_signum,
+	"copysign" => method Number::qs_copysign,
 	"<=>" => method Number::qs_cmp,
 	"()"  => method Number::qs_call,
 	"=="  => method Number::qs_eql,
+	"hash" => method Number::qs_hash,
+	"between" => method Number::qs_between,
 
 	"round" => method Number::qs_round,
 	"ceil"  => method Number::qs_ceil,
 	"floor" => method Number::qs_floor,
+	"trunc" => method Number::qs_trunc,
+	"to_int" => method Number::qs_to_int,
+	"to_float" => method Number::qs_to_float,
+	"to_scientific" => method Number::qs_to_scientific,
+	"to_json" => method Number::qs_to_json,
+	"set_display_precision" => function Number::qs_set_display_precision,
 	"sqrt"  => method Number::qs_sqrt,
 }
 
@@ -793,6 +1283,68 @@ mod tests {
 		assert_eq!(Number::ONE, Number(Inner::Integer(1)));
 	}
 
+	#[test]
+	fn float_and_integer() {
+		assert!(Number::float(3.0).is_float());
+		assert!(!Number::from(3.0).is_float());
+		assert!(Number::from(3.0).is_integer());
+		assert!(Number::integer(3).is_integer());
+		assert_eq!(Number::float(3.0), Number::integer(3));
+
+		assert!(Number::integer(3).qs_to_float(args!()).unwrap().is_float());
+		assert!(Number::float(2.5).qs_to_float(args!()).unwrap().is_float());
+		assert!(Number::float(3.0).qs_to_int(args!()).unwrap().is_integer());
+	}
+
+	#[test]
+	fn display_precision() {
+		let text = |n: Number| n.qs_at_text(args!()).unwrap().to_string();
+		let tenth = Number::from(0.1 + 0.2);
+
+		assert_eq!(Number::display_precision(), None);
+		assert_eq!(text(tenth), "0.30000000000000004");
+
+		Number::qs_set_display_precision(&Object::default(), args!(6)).unwrap();
+		assert_eq!(Number::display_precision(), Some(6));
+		assert_eq!(text(tenth), "0.3");
+		assert_eq!(text(Number::PI), "3.14159");
+		assert_eq!(text(Number::from(-1234.56789)), "-1234.57");
+		assert_eq!(text(Number::from(12345)), "12345");
+		assert_eq!(text(Number::INF), "inf");
+
+		// debug and `__inspect__` aren't affected
+		assert_eq!(format!("{:?}", tenth), "0.30000000000000004");
+		assert_eq!(tenth.qs___inspect__(args!()).unwrap().to_string(), "0.30000000000000004");
+
+		assert!(Number::qs_set_display_precision(&Object::default(), args!(0)).is_err());
+		assert!(Number::qs_set_display_precision(&Object::default(), args!(-1)).is_err());
+		assert_eq!(Number::display_precision(), Some(6));
+
+		let old = Number::qs_set_display_precision(&Object::default(), args!()).unwrap();
+		assert_eq!(old.downcast_call::<Number>().unwrap(), Number::from(6));
+		assert_eq!(Number::display_precision(), None);
+		assert_eq!(text(tenth), "0.30000000000000004");
+	}
+
+	#[test]
+	fn from_large_whole_float() {
+		assert!(Number::from(1e300).is_float());
+		assert!(Number::from(-1e300).is_float());
+		assert!(Number::from(1e15).is_integer());
+	}
+
+	#[test]
+	fn to_json() {
+		assert_eq!(Number::from(12).to_json().unwrap(), "12");
+		assert_eq!(Number::from(-3).to_json().unwrap(), "-3");
+		assert_eq!(Number::from(2.5).to_json().unwrap(), "2.5");
+		assert_eq!(Number::float(3.0).to_json().unwrap(), "3");
+
+		assert!(Number::NAN.to_json().is_err());
+		assert!(Number::INF.to_json().is_err());
+		assert!((-Number::INF).qs_to_json(args!()).is_err());
+	}
+
 	#[test]
 	fn default() {
 		assert_eq!(Number::default(), Number::ZERO);
@@ -856,5 +1408,291 @@ mod tests {
 		assert!(matches!(Number::try_from(" ").unwrap_err(), FromStrError::BadFloat(..)));
 	}
 
-}
+	#[test]
+	fn try_from_underscores() {
+		assert_eq!(Number::try_from("1_000_000").unwrap(), Number(Inner::Integer(1_000_000)));
+		assert_eq!(Number::try_from("-1_2.3_4").unwrap(), Number(Inner::Float(-12.34)));
+
+		assert_eq!(Number::try_from("_123").unwrap_err(), FromStrError::MisplacedUnderscore(0));
+		assert_eq!(Number::try_from("123_").unwrap_err(), FromStrError::MisplacedUnderscore(3));
+		assert_eq!(Number::try_from("1__2").unwrap_err(), FromStrError::MisplacedUnderscore(1));
+		assert_eq!(Number::try_from("1_.2").unwrap_err(), FromStrError::MisplacedUnderscore(1));
+		assert_eq!(Number::try_from(" 1_ ").unwrap_err(), FromStrError::MisplacedUnderscore(1));
+	}
+
+	#[test]
+	fn div_by_zero_is_nan() {
+		assert!(matches!((Number::ONE / Number::ZERO).0, Inner::Float(f) if f.is_nan()));
+		assert!(matches!(Number::ONE.qs_div(args!(0)).unwrap().0, Inner::Float(f) if f.is_nan()));
+	}
+
+	#[test]
+	fn checked_div() {
+		assert_eq!(Number::from(6).checked_div(Number::from(4)).unwrap(), Number::from(1.5));
+		assert_eq!(Number::from(6).qs_checked_div(args!(3)).unwrap(), Number::from(2));
+
+		assert_eq!(
+			Number::from(6).checked_div(Number::ZERO).unwrap_err(),
+			ValueError::DivisionByZero(Number::from(6))
+		);
+		assert_eq!(
+			Number::from(2.5).checked_div(Number::from(0.0)).unwrap_err(),
+			ValueError::DivisionByZero(Number::from(2.5))
+		);
+
+		let err = Number::from(7).qs_checked_div(args!(0)).unwrap_err();
+		assert_eq!(err, ValueError::DivisionByZero(Number::from(7)).into());
+		assert!(err.to_string().contains("7 / 0"), "bad message: {}", err);
+	}
+
+	#[test]
+	fn signum() {
+		assert!(matches!(Number::from(12).signum().0, Inner::Integer(1)));
+		assert!(matches!(Number::from(-12).signum().0, Inner::Integer(-1)));
+		assert!(matches!(Number::ZERO.signum().0, Inner::Integer(0)));
+
+		assert!(matches!(Number::from(2.5).signum().0, Inner::Float(f) if f == 1.0));
+		assert!(matches!(Number::from(-2.5).signum().0, Inner::Float(f) if f == -1.0));
+		assert!(matches!(Number::from(0.0).signum().0, Inner::Float(f) if f == 0.0 && f.is_sign_positive()));
+		assert!(matches!(Number::from(-0.0).signum().0, Inner::Float(f) if f == 0.0 && f.is_sign_negative()));
+		assert!(matches!(Number::NAN.signum().0, Inner::Float(f) if f.is_nan()));
+
+		assert_eq!(Number::from(-3).qs_signum(args!()).unwrap(), -Number::ONE);
+	}
 
+	#[test]
+	fn integer_min() {
+		let min = Number::from(IntegerType::MIN);
+		let max_plus_one = -(IntegerType::MIN as FloatType);
+
+		assert!(matches!(min.abs().0, Inner::Float(f) if f == max_plus_one));
+		assert!(matches!(min.signum().0, Inner::Integer(-1)));
+		assert!(matches!(min.copysign(Number::from(-1)).0, Inner::Integer(IntegerType::MIN)));
+		assert!(matches!(min.copysign(Number::ONE).0, Inner::Float(f) if f == max_plus_one));
+		assert!(matches!(Number::from(IntegerType::MAX).copysign(-Number::ONE).0, Inner::Integer(i) if i == -IntegerType::MAX));
+	}
+
+	#[test]
+	fn copysign() {
+		assert!(matches!(Number::from(3).copysign(Number::from(5)).0, Inner::Integer(3)));
+		assert!(matches!(Number::from(3).copysign(Number::from(-5)).0, Inner::Integer(-3)));
+		assert!(matches!(Number::from(-3).copysign(Number::ZERO).0, Inner::Integer(3)));
+		assert!(matches!(Number::from(3).copysign(Number::from(-0.0)).0, Inner::Integer(-3)));
+		assert!(matches!(Number::from(-3).copysign(Number::from(0.5)).0, Inner::Integer(3)));
+		assert!(matches!(Number::from(1.5).copysign(Number::from(-2)).0, Inner::Float(f) if f == -1.5));
+
+		assert_eq!(Number::from(-1.5).copysign(Number::from(2)), Number::from(1.5));
+		assert_eq!(Number::from(1.5).copysign(Number::from(-2.5)), Number::from(-1.5));
+		assert_eq!(Number::from(1.5).copysign(Number::from(0.0)), Number::from(1.5));
+		assert_eq!(Number::from(1.5).copysign(Number::from(-0.0)), Number::from(-1.5));
+		assert!(matches!(Number::from(0.0).copysign(Number::from(-1)).0, Inner::Float(f) if f.is_sign_negative()));
+
+		assert_eq!(Number::from(4).qs_copysign(args!(-1)).unwrap(), Number::from(-4));
+	}
+
+	#[test]
+	fn trunc() {
+		assert_eq!(Number::from(2.9).trunc(), 2);
+		assert_eq!(Number::from(2.9).floor(), 2);
+		assert_eq!(Number::from(-2.9).trunc(), -2);
+		assert_eq!(Number::from(-2.9).floor(), -3);
+		assert_eq!(Number::from(-7).trunc(), -7);
+		assert_eq!(Number::NAN.trunc(), 0);
+
+		assert!(matches!(Number::from(-2.5).qs_trunc(args!()).unwrap().0, Inner::Integer(-2)));
+		assert!(matches!(Number::from(-2.5).qs_floor(args!()).unwrap().0, Inner::Integer(-3)));
+		assert!(matches!(Number::from(3.75).qs_to_int(args!()).unwrap().0, Inner::Integer(3)));
+		assert!(matches!(Number::from(-3.75).qs_to_int(args!()).unwrap().0, Inner::Integer(-3)));
+	}
+
+	#[test]
+	fn between() {
+		let (low, high) = (Number::from(1), Number::from(5));
+
+		assert!(Number::from(3).between(low, high, false));
+		assert!(Number::from(1).between(low, high, false));
+		assert!(Number::from(5).between(low, high, false));
+		assert!(!Number::from(5.5).between(low, high, false));
+		assert!(!Number::from(0.5).between(low, high, false));
+
+		assert!(Number::from(1).between(low, high, true));
+		assert!(Number::from(4.9).between(low, high, true));
+		assert!(!Number::from(5).between(low, high, true));
+
+		assert!(!Number::from(3).between(high, low, false));
+		assert!(!Number::from(5).between(high, low, true));
+
+		assert!(!Number::NAN.between(low, high, false));
+		assert!(!Number::from(3).between(Number::NAN, high, false));
+		assert!(!Number::from(3).between(low, Number::NAN, true));
+	}
+
+	#[test]
+	fn qs_between() {
+		assert_eq!(Number::from(5).qs_between(args!(1, 5)).unwrap(), Boolean::TRUE);
+		assert_eq!(Number::from(5).qs_between(args!(1, 5, true)).unwrap(), Boolean::FALSE);
+		assert_eq!(Number::from(5).qs_between(args!(1, 5, false)).unwrap(), Boolean::TRUE);
+		assert_eq!(Number::from(3).qs_between(args!(5, 1)).unwrap(), Boolean::FALSE);
+		assert!(Number::from(3).qs_between(args!(1)).is_err());
+	}
+
+	#[test]
+	fn bad_operator_argument() {
+		use crate::error::TypeError;
+
+		let err = Number::ONE.qs_add(args!(Text::from("one"))).unwrap_err();
+		assert_eq!(err, TypeError::BadArgument {
+			method: "+",
+			index: 0,
+			expected: "Number",
+			got: "Text"
+		}.into());
+		assert!(err.to_string().contains("argument 0 to '+'"), "bad message: {}", err);
+
+		let err = Number::ONE.qs_between(args!(0, crate::types::Map::default())).unwrap_err();
+		assert!(err.to_string().contains("argument 1 to 'between'"), "bad message: {}", err);
+	}
+
+	#[test]
+	fn hash() {
+		assert_eq!(Number::from(12).qs_hash(args!()).unwrap(), Number::from(12).qs_hash(args!()).unwrap());
+		assert_ne!(Number::from(12).qs_hash(args!()).unwrap(), Number::from(13).qs_hash(args!()).unwrap());
+	}
+
+	#[test]
+	fn modulo() {
+		assert_eq!(Number::from(7).modulo(Number::from(3)), Number::from(1));
+		assert_eq!(Number::from(-1).modulo(Number::from(3)), Number::from(2));
+		assert_eq!(Number::from(-7).modulo(Number::from(3)), Number::from(2));
+		assert_eq!(Number::from(-6).modulo(Number::from(3)), Number::ZERO);
+		assert_eq!(Number::from(1).modulo(Number::from(-3)), Number::from(-2));
+		assert_eq!(Number::from(-1).modulo(Number::from(-3)), Number::from(-1));
+		assert_eq!(Number::from(-1.5).modulo(Number::from(1)), Number::from(0.5));
+		assert_eq!(Number::from(5.5).modulo(Number::from(-2)), Number::from(-0.5));
+
+		// `%` is unchanged
+		assert_eq!(Number::from(-1) % Number::from(3), Number::from(-1));
+
+		assert!(matches!(Number::from(5).modulo(Number::ZERO).0, Inner::Float(f) if f.is_nan()));
+		assert!(matches!(Number::from(5.5).modulo(Number::from(0.0)).0, Inner::Float(f) if f.is_nan()));
+
+		assert_eq!(Number::from(-1).qs_modulo(args!(3)).unwrap(), Number::from(2));
+	}
+
+	#[test]
+	fn bit_counting() {
+		let num = Number::from(0b1011_0000);
+
+		assert_eq!(num.qs_count_ones(args!()).unwrap(), Number::from(3));
+		assert_eq!(num.qs_leading_zeros(args!()).unwrap(), Number::from(56));
+		assert_eq!(num.qs_trailing_zeros(args!()).unwrap(), Number::from(4));
+
+		assert_eq!(Number::from(-1).qs_count_ones(args!()).unwrap(), Number::from(64));
+		assert_eq!(Number::from(-1).qs_leading_zeros(args!()).unwrap(), Number::ZERO);
+		assert_eq!(Number::ZERO.qs_trailing_zeros(args!()).unwrap(), Number::from(64));
+	}
+
+	#[test]
+	fn bit() {
+		let num = Number::from(0b101);
+
+		assert!(num.qs_bit(args!(0)).unwrap());
+		assert!(!num.qs_bit(args!(1)).unwrap());
+		assert!(num.qs_bit(args!(2)).unwrap());
+		assert!(!num.qs_bit(args!(63)).unwrap());
+		assert!(Number::from(-1).qs_bit(args!(63)).unwrap());
+
+		assert!(num.qs_bit(args!(64)).is_err());
+		assert!(num.qs_bit(args!(-1)).is_err());
+		assert!(num.qs_bit(args!(1.5)).is_err());
+	}
+
+	#[test]
+	fn bit_methods_reject_floats() {
+		let float = Number::from(1.5);
+		let not_an_integer = crate::Error::from(NotAnInteger(1.5).to_string());
+
+		assert_eq!(float.qs_count_ones(args!()).unwrap_err(), not_an_integer);
+		assert_eq!(float.qs_leading_zeros(args!()).unwrap_err(), not_an_integer);
+		assert_eq!(float.qs_trailing_zeros(args!()).unwrap_err(), not_an_integer);
+		assert_eq!(float.qs_bit(args!(0)).unwrap_err(), not_an_integer);
+	}
+
+	#[test]
+	fn checked_shifts() {
+		assert_eq!(Number::ONE.checked_shl(Number::from(63)).unwrap(), Number::from(IntegerType::MIN));
+		assert_eq!(Number::from(IntegerType::MIN).checked_shr(Number::from(63)).unwrap(), Number::from(-1));
+		assert_eq!(Number::from(12).qs_checked_shl(args!(2)).unwrap(), Number::from(48));
+		assert_eq!(Number::from(12).qs_checked_shr(args!(2)).unwrap(), Number::from(3));
+
+		let too_far = |amount| ValueError::Messaged(format!("shift amount must be between 0 and 63, not {}", amount));
+		assert_eq!(Number::ONE.checked_shl(Number::from(64)).unwrap_err(), too_far(64));
+		assert_eq!(Number::ONE.checked_shr(Number::from(64)).unwrap_err(), too_far(64));
+		assert_eq!(Number::ONE.checked_shl(Number::from(100)).unwrap_err(), too_far(100));
+		assert_eq!(Number::ONE.checked_shr(Number::from(100)).unwrap_err(), too_far(100));
+		assert_eq!(Number::ONE.checked_shl(Number::from(-1)).unwrap_err(), too_far(-1));
+
+		assert!(Number::from(1.5).checked_shl(Number::ONE).is_err());
+		assert!(Number::ONE.qs_checked_shr(args!(100)).is_err());
+	}
+
+	#[test]
+	fn at_list() {
+		fn digits(list: List) -> Vec<IntegerType> {
+			list.iter()
+				.map(|obj| obj.downcast_ref::<Number>().unwrap().floor())
+				.collect()
+		}
+
+		assert_eq!(digits(Number::from(9071).qs_at_list(args!()).unwrap()), [9, 0, 7, 1]);
+		assert_eq!(digits(Number::from(0).qs_at_list(args!()).unwrap()), [0]);
+		assert_eq!(digits(Number::from(-345).qs_at_list(args!()).unwrap()), [3, 4, 5]);
+		assert_eq!(digits(Number::from(0xf1e).qs_at_list(args!(16)).unwrap()), [15, 1, 14]);
+		assert_eq!(digits(Number::from(5).qs_at_list(args!(2)).unwrap()), [1, 0, 1]);
+		assert_eq!(
+			digits(Number::from(IntegerType::MIN).qs_at_list(args!(16)).unwrap()),
+			[8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+		);
+
+		assert_eq!(
+			Number::from(1.5).qs_at_list(args!()).unwrap_err(),
+			ValueError::Messaged(NotAnInteger(1.5).to_string()).into()
+		);
+		assert!(Number::from(12).qs_at_list(args!(1)).is_err());
+		assert!(Number::from(12).qs_at_list(args!(37)).is_err());
+		assert!(Number::from(12).qs_at_list(args!(-10)).is_err());
+	}
+
+	#[test]
+	fn to_string_radix_uncommon() {
+		assert_eq!(Number::from(35).to_string_radix(36).unwrap(), "z");
+		assert_eq!(Number::from(-7).to_string_radix(3).unwrap(), "-21");
+		assert_eq!(Number::from(12).to_string_radix(37).unwrap_err(), ToStringRadixError::InvalidRadix(37));
+		assert_eq!(Number::from(12).to_string_radix(1).unwrap_err(), ToStringRadixError::InvalidRadix(1));
+	}
+
+	#[test]
+	fn to_scientific() {
+		let sci = |num: Number, args: Args| num.qs_to_scientific(args).unwrap().to_string();
+
+		assert_eq!(sci(Number::from(12300.0), args!()), "1.23e4");
+		assert_eq!(sci(Number::from(12300), args!()), "1.23e4");
+		assert_eq!(sci(Number::from(0.00042), args!()), "4.2e-4");
+		assert_eq!(sci(Number::from(-5.0), args!()), "-5e0");
+		assert_eq!(sci(Number::from(0), args!()), "0e0");
+		assert_eq!(sci(Number::from(6.02e-23), args!()), "6.02e-23");
+		assert_eq!(sci(Number::from(IntegerType::MAX), args!()), "9.223372036854776e18");
+		assert_eq!(
+			sci(Number::from(FloatType::MIN_POSITIVE / 4.0), args!()),
+			"5.562684646268003e-309"
+		);
+		assert_eq!(sci(Number::from(5e-324), args!()), "5e-324");
+
+		assert_eq!(sci(Number::from(12345.678), args!(2)), "1.23e4");
+		assert_eq!(sci(Number::from(12), args!(3)), "1.200e1");
+		assert_eq!(sci(Number::from(0.00042), args!(0)), "4e-4");
+		assert_eq!(sci(Number::from(5e-324), args!(2.9)), "4.94e-324");
+
+		assert!(Number::from(1).qs_to_scientific(args!(-1)).is_err());
+	}
+}
@@ -61,8 +61,8 @@ impl Pristine {
 	/// # Quest Examples
 	/// ```quest
 	/// assert(  == 1.$__inspect__() == "1" );
-	/// assert(  == 2.$__inspect__() == '"2"' );
-	/// assert(  == ["2", 3].$__inspect__() == '["2", 3]' );
+	/// assert(  == 2.$__inspect__() == "\"2\"" );
+	/// assert(  == ["2", 3].$__inspect__() == "[\"2\", 3]" );
 	/// ```
 	#[allow(non_snake_case)]
 	pub fn qs___inspect__(this: &Object, _: Args) -> Result<Text, !> {
@@ -134,6 +134,24 @@ impl Pristine {
 		Ok(val.clone())
 	}
 
+	/// Set an attribute on the object, returning the object itself so calls can be chained.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The attribute to set.
+	/// 2. (required) The value to set it to.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $obj = Basic().$__set_attr_chain__($x, 1).$__set_attr_chain__($y, 2);
+	/// assert( obj.$x + obj.$y == 3 );
+	/// ```
+	#[allow(non_snake_case)]
+	pub fn qs___set_attr_chain__(this: &Object, args: Args) -> crate::Result<Object> {
+		Pristine::qs___set_attr__(this, args)?;
+		Ok(this.clone())
+	}
+
 	#[inline]
 	#[allow(non_snake_case)]
 	pub fn qs___has_attr__(this: &Object, args: Args) -> crate::Result<bool> {
@@ -165,19 +183,109 @@ impl Pristine {
 		this.dot_get_attr(attr)
 	}
 
+	/// Checks to see if the argument is the exact same object as this, rather than just equal to it.
+	///
+	/// Unlike `==`, no built-in type overrides this, so it's based solely on the object's
+	/// identity. (Like any attribute, it can still be shadowed by setting `===` on an object or one
+	/// of its parents.)
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The object to compare against.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// $x = 3;
+	/// $y = x;
+	/// assert( x == 3 );
+	/// assert( !x.$===(3) );
+	/// assert( x.$===(y) );
+	/// ```
+	#[inline]
+	pub fn qs_identical(this: &Object, args: Args) -> crate::Result<Boolean> {
+		let rhs = args.arg(0)?;
+		Ok(this.is_identical(rhs).into())
+	}
+
+	/// Calls the given callable with this object, then returns this object.
+	///
+	/// The callable's result is ignored, which makes this useful for side effects (such as logging)
+	/// in the middle of a chain of method calls.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The callable, which is passed this object.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert( 3.$tap({ disp(_0) }) == 3 ); # => 3
+	/// ```
+	pub fn qs_tap(this: &Object, args: Args) -> crate::Result<Object> {
+		args.arg(0)?.call_attr_lit("()", &[this])?;
+		Ok(this.clone())
+	}
+
+	/// Calls the given callable with this object, returning its result.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The callable, which is passed this object.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert( 3.$pipe({ _0 * 2 }) == 6 );
+	/// ```
+	pub fn qs_pipe(this: &Object, args: Args) -> crate::Result<Object> {
+		args.arg(0)?.call_attr_lit("()", &[this])
+	}
+
+	/// Gets the name of the type of data this object holds, without its module path.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert( "foo".$typename() == "Text" );
+	/// ```
+	#[inline]
+	pub fn qs_typename(this: &Object, _: Args) -> Result<Text, !> {
+		Ok(Text::new_static(this.short_typename()))
+	}
+
+	/// Checks to see if the given object is anywhere in this object's parent chain.
+	///
+	/// # Arguments
+	///
+	/// 1. (required) The possible ancestor.
+	///
+	/// # Quest Examples
+	/// ```quest
+	/// assert( 3.$is_a(Number) );
+	/// assert( 3.$is_a(Comparable) );
+	/// assert( !3.$is_a(Text) );
+	/// ```
+	pub fn qs_is_a(this: &Object, args: Args) -> crate::Result<Boolean> {
+		let ancestor = args.arg(0)?;
+		Ok(this.is_descendant_of(ancestor)?.into())
+	}
+
+	/// Gets all the keys of this object.
+	///
+	/// # Arguments
+	///
+	/// 1. (optional) Whether to include the parents, defaults to `false`.
+	/// 2. (optional) Whether to sort the keys, defaults to `false`. If not given, the order of the
+	///    keys is unspecified.
 	#[allow(non_snake_case)]
 	pub fn qs___keys__(this: &Object, args: Args) -> crate::Result<Object> {
-		let include_parents = args.arg(0)
+		let flag = |idx| args.arg(idx)
 			.ok()
 			.and_then(|x| x.downcast_call::<Boolean>().ok())
 			.map(bool::from)
 			.unwrap_or(false);
 
-		Ok(this.mapping_keys(include_parents)
-			.into_iter()
-			.map(Object::from)
-			.collect::<Vec<_>>()
-			.into())
+		let include_parents = flag(0);
+		let sorted = flag(1);
+
+		Ok(this.mapping_keys(include_parents, sorted)?.into())
 	}
 }
 
@@ -188,13 +296,200 @@ for Pristine [(init_parent) (parents Pristine)]:
 	"__call_attr__" => function Pristine::qs___call_attr__,
 	"__get_attr__" => function Pristine::qs___get_attr__,
 	"__set_attr__" => function Pristine::qs___set_attr__,
+	"__set_attr_chain__" => function Pristine::qs___set_attr_chain__,
 	"__has_attr__" => function Pristine::qs___has_attr__,
 	"__del_attr__" => function Pristine::qs___del_attr__,
 	"::" => function Pristine::qs___get_attr__,
 	".=" => function Pristine::qs___set_attr__,
 	"::@" => function Pristine::qs_root_get_attr,
 	"." => function Pristine::qs_dot_get_attr,
+	"===" => function Pristine::qs_identical,
+	"tap" => function Pristine::qs_tap,
+	"pipe" => function Pristine::qs_pipe,
+	"typename" => function Pristine::qs_typename,
+	"is_a" => function Pristine::qs_is_a,
 }
 
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::types::List;
+
+	fn keys(obj: &Object, sorted: bool) -> Vec<String> {
+		Pristine::qs___keys__(obj, args!(false, sorted)).unwrap()
+			.downcast_ref::<List>().unwrap()
+			.iter()
+			.map(|key| key.downcast_call::<Text>().unwrap().to_string())
+			.collect()
+	}
+
+	#[test]
+	fn keys_sorted() {
+		let obj = Object::new(Pristine);
+		for attr in &["zeta", "alpha", "mu", "beta", "omega", "gamma"] {
+			obj.set_attr_lit(attr, Object::default());
+		}
+		// non-literal keys come afterwards, in insertion order.
+		obj.set_attr(Object::from(2), Object::default()).unwrap();
+		obj.set_attr(Object::from(1), Object::default()).unwrap();
+
+		let expected = vec![
+			"__parents__", "__id__", "alpha", "beta", "gamma", "mu", "omega", "zeta", "2", "1"
+		];
+
+		for _ in 0..10 {
+			assert_eq!(keys(&obj, true), expected);
+		}
+	}
+
+	#[test]
+	fn keys_unsorted() {
+		let obj = Object::new(Pristine);
+		obj.set_attr_lit("foo", Object::default());
+		obj.set_attr_lit("bar", Object::default());
+
+		let mut keys = keys(&obj, false);
+		keys.sort();
+		assert_eq!(keys, vec!["__id__", "__parents__", "bar", "foo"]);
+	}
+
+	#[test]
+	fn identical() {
+		use crate::types::Number;
+
+		let lhs = Object::from(Number::from(3));
+		let rhs = Object::from(Number::from(3));
+		let same = lhs.clone();
+
+		assert!(lhs.eq_obj(&rhs).unwrap());
+		assert!(!bool::from(Pristine::qs_identical(&lhs, args!(rhs.clone())).unwrap()));
+
+		assert!(lhs.eq_obj(&same).unwrap());
+		assert!(bool::from(Pristine::qs_identical(&lhs, args!(same.clone())).unwrap()));
+
+		// it's inherited by everything
+		assert!(bool::from(lhs.call_attr_lit("===", &[&same]).unwrap()
+			.downcast_call::<Boolean>().unwrap()));
+		assert!(!bool::from(lhs.call_attr_lit("===", &[&rhs]).unwrap()
+			.downcast_call::<Boolean>().unwrap()));
+	}
+
+	#[test]
+	fn tap_and_pipe() {
+		use crate::types::{Number, RustFn};
+		use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+		let calls = Arc::new(AtomicUsize::new(0));
+		let double = {
+			let calls = calls.clone();
+			Object::from(RustFn::new_boxed("double", move |this, _| {
+				calls.fetch_add(1, Ordering::SeqCst);
+				Ok((*this.downcast_ref::<Number>().unwrap() * 2.into()).into())
+			}))
+		};
+
+		let three = Object::from(Number::from(3));
 
+		let tapped = Pristine::qs_tap(&three, args!(double.clone())).unwrap();
+		assert!(tapped.is_identical(&three));
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
 
+		let piped = Pristine::qs_pipe(&three, args!(double.clone())).unwrap();
+		assert_eq!(*piped.downcast_ref::<Number>().unwrap(), Number::from(6));
+		assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+		// `then` belongs to `Boolean`, so it isn't an alias for `pipe`.
+		assert!(three.call_attr_lit("then", &[&double]).is_err());
+		assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+		assert!(Pristine::qs_tap(&three, args!()).is_err());
+	}
+
+	#[test]
+	fn typename_and_is_a() {
+		use crate::types::{Number, Basic, Comparable, ObjectType};
+
+		let num = Object::from(Number::from(3));
+		assert_eq!(
+			Pristine::qs_typename(&num, args!()).unwrap(),
+			Text::from("Number")
+		);
+
+		let is_a = |ancestor: Object| bool::from(Pristine::qs_is_a(&num, args!(ancestor)).unwrap());
+
+		assert!(is_a(Number::mapping()));
+		assert!(is_a(Basic::mapping()));
+		assert!(is_a(Comparable::mapping()));
+		assert!(is_a(Pristine::mapping()));
+		assert!(!is_a(Text::mapping()));
+		assert!(!is_a(Object::from(Number::from(3))));
+	}
+
+	#[test]
+	fn set_attr_chain() {
+		let obj = Object::new(Pristine);
+
+		let ret = Pristine::qs___set_attr_chain__(&obj, args!("foo", 1)).unwrap();
+		assert!(ret.is_identical(&obj));
+
+		Pristine::qs___set_attr_chain__(&ret, args!("foo", 2)).unwrap();
+		assert_eq!(keys(&obj, true), vec!["__parents__", "__id__", "foo"]);
+		assert_eq!(
+			*obj.get_attr_lit("foo").unwrap().downcast_ref::<crate::types::Number>().unwrap(),
+			2.into()
+		);
+
+		// `__set_attr__` still returns the value
+		let val = Pristine::qs___set_attr__(&obj, args!("bar", 3)).unwrap();
+		assert_eq!(*val.downcast_ref::<crate::types::Number>().unwrap(), 3.into());
+	}
+
+	#[test]
+	fn set_attr_no_duplicate_keys() {
+		use crate::types::{Basic, Number, RustFn};
+
+		let obj = Object::new(Pristine);
+		Pristine::qs___set_attr__(&obj, args!(1, "a")).unwrap();
+		Pristine::qs___set_attr__(&obj, args!(1, "b")).unwrap();
+		assert_eq!(keys(&obj, true), vec!["__parents__", "__id__", "1"]);
+
+		// a non-text key that's equal to (and hashes the same as) a literal key refers to the
+		// same entry
+		let foo = Object::new(Basic);
+		foo.set_attr_lit("==", RustFn::new_boxed("==", |_, args| {
+			Ok(args.arg(0)?.downcast_ref::<Text>().is_some_and(|t| t.as_ref() == "foo").into())
+		}));
+		foo.set_attr_lit("hash", RustFn::new_boxed("hash", |_, _| {
+			Ok(crate::utils::stable_hash(&Text::from("foo")).into())
+		}));
+
+		Pristine::qs___set_attr__(&obj, args!("foo", 1)).unwrap();
+		Pristine::qs___set_attr__(&obj, args!(foo.clone(), 2)).unwrap();
+		assert_eq!(keys(&obj, true), vec!["__parents__", "__id__", "foo", "1"]);
+
+		assert_eq!(*obj.get_attr_lit("foo").unwrap().downcast_ref::<Number>().unwrap(), 2.into());
+		assert_eq!(*obj.get_attr(&foo).unwrap().downcast_ref::<Number>().unwrap(), 2.into());
+
+		obj.del_attr(&foo).unwrap();
+		assert_eq!(keys(&obj, true), vec!["__parents__", "__id__", "1"]);
+	}
+
+	#[test]
+	fn set_attr_erroring_eql() {
+		use crate::types::{Basic, Number, RustFn};
+
+		// a key whose `==` always fails mustn't break other lookups.
+		let bad = Object::new(Basic);
+		bad.set_attr_lit("==", RustFn::new_boxed("==", |_, _| {
+			Err(crate::error::ValueError::Messaged("nope".into()).into())
+		}));
+
+		let obj = Object::new(Pristine);
+		Pristine::qs___set_attr__(&obj, args!("foo", 1)).unwrap();
+		Pristine::qs___set_attr__(&obj, args!(bad.clone(), 2)).unwrap();
+		Pristine::qs___set_attr__(&obj, args!("bar", 3)).unwrap();
+
+		assert_eq!(*obj.get_attr_lit("foo").unwrap().downcast_ref::<Number>().unwrap(), 1.into());
+		assert_eq!(*obj.get_attr(&"bar".into()).unwrap().downcast_ref::<Number>().unwrap(), 3.into());
+	}
+}
@@ -10,49 +10,101 @@ use crate::Object;
 use crate::types::Text;
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 // use std::any::Any;
 
-#[derive(Clone, Copy)]
-pub struct RustFn(&'static str, fn(&Object, Args) -> crate::Result<Object>);
+type FnPointer = fn(&Object, Args) -> crate::Result<Object>;
+type BoxedFn = Arc<dyn Fn(&Object, Args) -> crate::Result<Object> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct RustFn(&'static str, Func);
+
+#[derive(Clone)]
+enum Func {
+	Pointer(FnPointer),
+	Boxed(BoxedFn)
+}
+
+impl Func {
+	#[inline]
+	fn addr(&self) -> usize {
+		match self {
+			Func::Pointer(func) => *func as usize,
+			Func::Boxed(func) => Arc::as_ptr(func) as *const () as usize
+		}
+	}
+}
 
 impl Debug for RustFn {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		f.debug_tuple("RustFn").field(&self.0).finish()
+		match self.1 {
+			Func::Pointer(_) => f.debug_tuple("RustFn").field(&self.0).finish(),
+			Func::Boxed(_) => f.debug_tuple("RustFn").field(&self.0).field(&"<closure>").finish()
+		}
 	}
 }
 
 impl Eq for RustFn {}
 impl PartialEq for RustFn {
+	/// Function pointers are compared by address, and closures by their `Arc`'s pointer.
 	#[inline]
 	fn eq(&self, rhs: &RustFn) -> bool {
-		let eql = (self.1 as usize) == (rhs.1 as usize);
-		debug_assert_eq!(eql, self.0 == rhs.0);
-		eql
+		match (&self.1, &rhs.1) {
+			(Func::Pointer(_), Func::Pointer(_)) => {
+				let eql = self.1.addr() == rhs.1.addr();
+				debug_assert_eq!(eql, self.0 == rhs.0);
+				eql
+			},
+			(Func::Boxed(lhs), Func::Boxed(rhs)) => Arc::ptr_eq(lhs, rhs),
+			_ => false
+		}
 	}
 }
 
 impl Hash for RustFn {
 	#[inline]
 	fn hash<H: Hasher>(&self, h: &mut H) {
-		(self.1 as usize).hash(h)
+		self.1.addr().hash(h)
 	}
 }
 
 impl RustFn {
 	#[inline]
-	pub fn new(name: &'static str, func: fn(&Object, Args) -> crate::Result<Object>) -> Self {
-		RustFn(name, func)
+	pub fn new(name: &'static str, func: FnPointer) -> Self {
+		RustFn(name, Func::Pointer(func))
+	}
+
+	/// Create a new [`RustFn`](#) from a closure, which, unlike [`new`](#method.new), is able to
+	/// capture state.
+	pub fn new_boxed<F>(name: &'static str, func: F) -> Self
+	where
+		F: Fn(&Object, Args) -> crate::Result<Object> + Send + Sync + 'static
+	{
+		RustFn(name, Func::Boxed(Arc::new(func)))
 	}
 
 	#[inline]
 	// eventually, we'll remove the `generic` thing.
 	pub fn call(&self, obj: &Object, args: Args) -> crate::Result<Object> {
-		(self.1)(obj, args)
+		use crate::error::{Error, TypeError};
+
+		let result =
+			match self.1 {
+				Func::Pointer(func) => func(obj, args),
+				Func::Boxed(ref func) => func(obj, args)
+			};
+
+		// `Args::arg` doesn't know which function it's in, so we fill it in for it.
+		result.map_err(|err| match err {
+			Error::TypeError(TypeError::ArgumentCount { method: None, missing, got }) =>
+				TypeError::ArgumentCount { method: Some(self.0), missing, got }.into(),
+			err => err
+		})
 	}
 
 	#[inline]
 	pub fn call_old(&self, args: ArgsOld) -> crate::Result<Object> {
-		(self.1)(args.this()?, args.args(..)?.as_ref().iter().collect())
+		self.call(args.this()?, args.args(..)?.as_ref().iter().collect())
 	}
 }
 
@@ -73,7 +125,7 @@ impl RustFn {
 
 	#[inline]
 	pub fn qs_at_text(&self, _: Args) -> Result<Text, !> {
-		Ok(Text::from(*self))
+		Ok(Text::from(self.clone()))
 	}
 
 	#[inline]
@@ -90,4 +142,56 @@ for RustFn [(parents super::Function)]:
 	"__inspect__" => method RustFn::qs___inspect__,
 	"@text" => method RustFn::qs_at_text,
 	"()" => method RustFn::qs_call,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	#[test]
+	fn new_boxed() {
+		let counter = Arc::new(AtomicUsize::new(0));
+		let rustfn = {
+			let counter = counter.clone();
+			RustFn::new_boxed("count", move |_, args| {
+				let by = args.arg(0).map(|_| 2).unwrap_or(1);
+				Ok((counter.fetch_add(by, Ordering::SeqCst) + by).into())
+			})
+		};
+
+		rustfn.call(&Object::default(), Args::default()).unwrap();
+		rustfn.call(&Object::default(), Args::default()).unwrap();
+		assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+		let result = rustfn.call(&Object::default(), args!(true)).unwrap();
+		assert_eq!(*result.downcast_ref::<crate::types::Number>().unwrap(), 4.into());
+		assert_eq!(counter.load(Ordering::SeqCst), 4);
+
+		// calling it through an object works, too
+		let obj = Object::from(rustfn);
+		obj.call_attr_lit("()", &[&Object::default()]).unwrap();
+		assert_eq!(counter.load(Ordering::SeqCst), 5);
+	}
+
+	#[test]
+	fn eq() {
+		fn noop(_: &Object, _: Args) -> crate::Result<Object> { Ok(Object::default()) }
+
+		assert_eq!(RustFn::new("noop", noop), RustFn::new("noop", noop));
+
+		let boxed = RustFn::new_boxed("noop", |_, _| Ok(Object::default()));
+		assert_eq!(boxed, boxed.clone());
+		assert_ne!(boxed, RustFn::new_boxed("noop", |_, _| Ok(Object::default())));
+		assert_ne!(boxed, RustFn::new("noop", noop));
+	}
+
+	#[test]
+	fn debug() {
+		let boxed = RustFn::new_boxed("foo", |_, _| Ok(Object::default()));
+
+		assert_eq!(format!("{:?}", boxed), r#"RustFn("foo", "<closure>")"#);
+		assert_eq!(Text::from(boxed).as_ref(), "foo");
+	}
+}
+
@@ -1,24 +1,38 @@
 use std::slice::SliceIndex;
 use crate::{Object, types};
-use crate::error::KeyError;
+use crate::error::{Error, KeyError, TypeError};
+use crate::types::Convertible;
 use std::borrow::Cow;
 use std::iter::FromIterator;
+use std::collections::HashMap;
 
 
+/// The arguments passed to a function: positional arguments, and any keyword arguments (which
+/// are given with `name: value` in Quest).
 #[derive(Clone, Default)]
-pub struct Args<'s, 'o: 's>(Cow<'s, [&'o Object]>);
+pub struct Args<'s, 'o: 's>(Cow<'s, [&'o Object]>, HashMap<String, &'o Object>);
 
 use std::fmt::{self, Debug, Formatter};
 
 impl Debug for Args<'_, '_> {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		Debug::fmt(&self.0, f)
+		if self.1.is_empty() {
+			Debug::fmt(&self.0, f)
+		} else {
+			f.debug_tuple("Args").field(&self.0).field(&self.1).finish()
+		}
 	}
 }
 
 impl<'s, 'o: 's> Args<'s, 'o> {
 	pub fn new<V: Into<Cow<'s, [&'o Object]>>>(args: V) -> Self {
-		Args(args.into())
+		Args(args.into(), HashMap::new())
+	}
+
+	/// Add the keyword argument `name`, replacing any previous value it had.
+	pub fn with_kwarg<N: Into<String>>(mut self, name: N, value: &'o Object) -> Self {
+		self.1.insert(name.into(), value);
+		self
 	}
 
 	pub fn into_inner(self) -> Cow<'s, [&'o Object]> {
@@ -38,6 +52,7 @@ impl<'s, 'o: 's> Args<'s, 'o> {
 	}
 }
 
+/// Only the positional arguments are kept.
 impl From<Args<'_, '_>> for Vec<Object> {
 	fn from(args: Args) -> Self {
 		args.0.iter().map(|x| (*x).clone()).collect()
@@ -104,21 +119,172 @@ impl<'s, 'o: 's> IntoIterator for Args<'s, 'o> {
 }
 
 impl<'o> Args<'_, 'o> {
-	pub fn arg(&self, idx: usize) -> Result<&'o Object, KeyError> {
+	/// Get argument `idx`, or a [`TypeError::ArgumentCount`] (saying `idx` is missing) if there
+	/// aren't enough arguments.
+	///
+	/// The error doesn't know which method was called; [`RustFn`](crate::types::RustFn)s fill
+	/// that in when this is used within them.
+	pub fn arg(&self, idx: usize) -> Result<&'o Object, TypeError> {
 		self.0.get(idx)
-			.map(|x| *x)
-			.ok_or_else(|| KeyError::OutOfBounds { idx: idx as isize, len: self.0.len() })
-	}	
+			.copied()
+			.ok_or(TypeError::ArgumentCount { method: None, missing: idx, got: self.0.len() })
+	}
+
+	/// Get argument `idx`, converted to a `T` by calling its conversion function.
+	///
+	/// Unlike calling [`downcast_call`](../../struct.Object.html#method.downcast_call) directly,
+	/// a failed conversion is reported as a [`TypeError::BadArgument`] that names `method`, which
+	/// argument it was, and what it should've been.
+	pub fn arg_as<T: Convertible>(&self, idx: usize, method: &'static str) -> crate::Result<T> {
+		let arg = self.arg(idx).map_err(|err| match err {
+			TypeError::ArgumentCount { missing, got, .. } =>
+				TypeError::ArgumentCount { method: Some(method), missing, got },
+			err => err
+		})?;
 
+		arg.downcast_call::<T>().map_err(|err| match err {
+			err @ Error::Return { .. } => err,
+			_ => TypeError::BadArgument {
+				method,
+				index: idx,
+				expected: crate::utils::short_type_name(std::any::type_name::<T>()),
+				got: arg.short_typename()
+			}.into()
+		})
+	}
+
+	/// Get a range of the positional arguments. The keyword arguments are kept as they are.
 	pub fn args<I>(&self, idx: I) -> Result<Args<'_, 'o>, KeyError>
 	where
 		I: SliceIndex<[&'o Object], Output=[&'o Object]> + fmt::Debug + Clone
 	{
 		if let Some(rng) = self.0.get(idx.clone()) {
-			Ok(rng.into())
+			Ok(Args(rng.into(), self.1.clone()))
 		} else {
 			Err(KeyError::BadSlice { slice: format!("{:?}", idx), len: self.0.len() })
 		}
 	}
+
+	/// Collect every positional argument from `start` onwards into a [`List`](../struct.List.html),
+	/// cloning each one.
+	///
+	/// If there aren't any arguments past `start`, the list is empty.
+	pub fn rest_as_list(&self, start: usize) -> types::List {
+		self.0.get(start..)
+			.map(|rest| rest.iter().map(|obj| (*obj).clone()).collect::<Vec<_>>())
+			.unwrap_or_default()
+			.into()
+	}
+
+	/// Get the keyword argument `name`, if it was given.
+	pub fn kwarg(&self, name: &str) -> Option<&'o Object> {
+		self.1.get(name).copied()
+	}
+
+	/// Get the keyword argument `name`, or `default` if it wasn't given.
+	pub fn kwarg_or<'a>(&self, name: &str, default: &'a Object) -> &'a Object
+	where
+		'o: 'a
+	{
+		self.kwarg(name).unwrap_or(default)
+	}
+
+	/// Iterate over all the keyword arguments, in no particular order.
+	pub fn kwargs(&self) -> impl Iterator<Item=(&str, &'o Object)> + '_ {
+		self.1.iter().map(|(name, value)| (name.as_str(), *value))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn positional_only() {
+		let (one, two) = (Object::from(1), Object::from(2));
+		let args = Args::new(vec![&one, &two]);
+
+		assert!(args.arg(0).unwrap().is_identical(&one));
+		assert!(args.arg(1).unwrap().is_identical(&two));
+		assert!(args.arg(2).is_err());
+		assert!(args.kwarg("digits").is_none());
+		assert_eq!(args.kwargs().count(), 0);
+	}
+
+	#[test]
+	fn keywords() {
+		let (num, digits, default) = (Object::from(1.234), Object::from(2), Object::from(0));
+		let args = Args::new(vec![&num]).with_kwarg("digits", &digits);
+
+		assert_eq!(args.as_ref().len(), 1);
+		assert!(args.arg(0).unwrap().is_identical(&num));
+		assert!(args.arg(1).is_err());
+
+		assert!(args.kwarg("digits").unwrap().is_identical(&digits));
+		assert!(args.kwarg("precision").is_none());
+		assert!(args.kwarg_or("digits", &default).is_identical(&digits));
+		assert!(args.kwarg_or("precision", &default).is_identical(&default));
+
+		// slicing the positional arguments keeps the keywords.
+		let rest = args.args(1..).unwrap();
+		assert!(rest.as_ref().is_empty());
+		assert!(rest.kwarg("digits").unwrap().is_identical(&digits));
+	}
+
+	#[test]
+	fn keywords_are_replaced() {
+		let (first, second) = (Object::from(1), Object::from(2));
+		let args = Args::default()
+			.with_kwarg("x", &first)
+			.with_kwarg("x", &second);
+
+		assert!(args.kwarg("x").unwrap().is_identical(&second));
+		assert_eq!(args.kwargs().count(), 1);
+	}
+
+	#[test]
+	fn arg_as() {
+		let (num, text) = (Object::from(12), Object::from(types::Text::from("twelve")));
+		let args = Args::new(vec![&num, &text]);
+
+		assert_eq!(args.arg_as::<types::Number>(0, "foo").unwrap(), types::Number::from(12));
+		assert_eq!(
+			args.arg_as::<types::Number>(2, "foo").unwrap_err(),
+			TypeError::ArgumentCount { method: Some("foo"), missing: 2, got: 2 }.into()
+		);
+
+		let err = args.arg_as::<types::Number>(1, "foo").unwrap_err();
+		assert_eq!(err, TypeError::BadArgument {
+			method: "foo",
+			index: 1,
+			expected: "Number",
+			got: "Text"
+		}.into());
+	}
+
+	#[test]
+	fn rest_as_list() {
+		let (one, two, three) = (Object::from(1), Object::from(2), Object::from(3));
+		let args = Args::new(vec![&one, &two, &three]);
+
+		let rest = args.rest_as_list(1);
+		assert_eq!(rest.len(), 2);
+		assert!(rest.as_ref()[0].is_identical(&two));
+		assert!(rest.as_ref()[1].is_identical(&three));
+
+		// the original arguments are left alone
+		assert_eq!(args.as_ref().len(), 3);
+		assert_eq!(args.rest_as_list(0).len(), 3);
+	}
+
+	#[test]
+	fn rest_as_list_empty() {
+		let one = Object::from(1);
+		let args = Args::new(vec![&one]);
+
+		assert!(args.rest_as_list(1).is_empty());
+		assert!(args.rest_as_list(5).is_empty());
+		assert!(Args::default().rest_as_list(0).is_empty());
+	}
 }
 
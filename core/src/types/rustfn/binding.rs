@@ -1,5 +1,6 @@
 use crate::{Object, Args, types};
 use std::sync::RwLock;
+use std::cell::Cell;
 use std::ops::Deref;
 
 type Stack = Vec<Binding>;
@@ -14,7 +15,30 @@ impl Default for Binding {
 	}
 }
 
+thread_local!(
+	static MAX_DEPTH: Cell<usize> = const { Cell::new(Binding::DEFAULT_MAX_DEPTH) };
+);
+
 impl Binding {
+	/// The default maximum number of stackframes a thread can have.
+	pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+	/// Get the maximum number of stackframes the current thread can have.
+	#[inline]
+	pub fn max_depth() -> usize {
+		MAX_DEPTH.with(Cell::get)
+	}
+
+	/// Set the maximum number of stackframes the current thread can have.
+	///
+	/// Once this is reached, [`new_stackframe`](#method.new_stackframe) will return a
+	/// [`StackOverflow`](../error/enum.ValueError.html#variant.StackOverflow) error instead of
+	/// recursing further.
+	#[inline]
+	pub fn set_max_depth(max_depth: usize) {
+		MAX_DEPTH.with(|depth| depth.set(max_depth))
+	}
+
 	#[inline]
 	pub fn try_instance() -> Option<Binding> {
 		Binding::with_stack(|stack| {
@@ -101,7 +125,22 @@ impl Binding {
 		})
 	}
 
+	#[inline]
 	pub fn new_stackframe<F>(parent: Option<Object>, args: Args, func: F) -> crate::Result<Object>
+	where
+		F: FnOnce(&Binding) -> crate::Result<Object>,
+	{
+		Binding::new_stackframe_at(parent, args, None, func)
+	}
+
+	/// Identical to [`new_stackframe`](#method.new_stackframe), except any errors that escape the
+	/// stackframe are given `location`, if they don't have one already.
+	pub fn new_stackframe_at<F>(
+		parent: Option<Object>,
+		args: Args,
+		location: Option<crate::error::Location>,
+		func: F
+	) -> crate::Result<Object>
 	where
 		F: FnOnce(&Binding) -> crate::Result<Object>,
 	{
@@ -124,6 +163,11 @@ impl Binding {
 					binding.set_attr(Object::from(format!("_{}", i)), (*arg).clone())?;
 				}
 
+				// keyword arguments become local variables.
+				for (name, value) in args.kwargs() {
+					binding.set_attr(Object::from(name.to_string()), value.clone())?;
+				}
+
 				binding.set_attr_lit("__args__", Object::from(types::List::from(args)));
 
 				if let Some(callee) = stack.read().expect("bad stack").last() {
@@ -136,6 +180,12 @@ impl Binding {
 
 			{
 				let mut stack = stack.write().expect("stack poisoned");
+				let max_depth = Binding::max_depth();
+
+				if max_depth <= stack.len() {
+					return Err(crate::error::ValueError::StackOverflow { max_depth }.into());
+				}
+
 				stack.push(binding.clone());
 			};
 
@@ -145,6 +195,10 @@ impl Binding {
 			match func(&binding) {
 				Err(crate::Error::Return { to, obj }) if to.as_ref().eq_obj(binding.as_ref())?
 					=> Ok(obj),
+				Err(err) => Err(match location {
+					Some(location) => err.at(location),
+					None => err
+				}),
 				other => other
 			}
 		})
@@ -188,4 +242,46 @@ impl Deref for Binding {
 	fn deref(&self) -> &Object {
 		&self.0
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn recurse(depth: &mut usize) -> crate::Result<Object> {
+		Binding::new_stackframe(None, Args::default(), |_| {
+			*depth += 1;
+			recurse(depth)
+		})
+	}
+
+	#[test]
+	fn stack_overflow() {
+		Binding::set_max_depth(50);
+
+		let mut depth = 0;
+		let err = recurse(&mut depth).unwrap_err();
+
+		assert_eq!(err, crate::error::ValueError::StackOverflow { max_depth: 50 }.into());
+		// the thread's global binding is the first stackframe.
+		assert_eq!(depth, 49);
+
+		// make sure the stack was unwound properly
+		assert_eq!(Binding::stack().len(), 1);
+
+		Binding::set_max_depth(Binding::DEFAULT_MAX_DEPTH);
+	}
+
+	#[test]
+	fn max_depth() {
+		assert_eq!(Binding::max_depth(), Binding::DEFAULT_MAX_DEPTH);
+		Binding::set_max_depth(12);
+		assert_eq!(Binding::max_depth(), 12);
+
+		// the limit is per-thread
+		std::thread::spawn(|| assert_eq!(Binding::max_depth(), Binding::DEFAULT_MAX_DEPTH))
+			.join()
+			.unwrap();
+	}
+}
+
@@ -1,5 +1,5 @@
 use crate::{Object, Result, Args};
-use crate::types::Text;
+use crate::types::{Text, List};
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Scope;
@@ -13,6 +13,21 @@ impl Scope {
 		}
 	}
 
+	/// Get the names of the variables bound directly in this scope, sorted.
+	///
+	/// Unlike `__keys__`, attributes inherited from parents are left out, as are internal
+	/// `__dunder__` attributes such as `__args__`.
+	pub fn qs_locals(this: &Object, _: Args) -> Result<List> {
+		let locals = this.mapping_keys(false, true)?
+			.into_iter()
+			.filter(|key| key.downcast_ref::<Text>()
+				.map(|name| !(name.as_ref().starts_with("__") && name.as_ref().ends_with("__")))
+				.unwrap_or(false))
+			.collect::<Vec<_>>();
+
+		Ok(List::from(locals))
+	}
+
 	pub fn qs_super(_this: &Object, _args: Args) -> Result<Object> {
 		// let attr = args.arg(0)?;
 		// let mut args = args.args(1..)?;
@@ -58,4 +73,30 @@ for Scope /*{
 [(parents super::Basic)]:
 	"@text" => function Scope::qs_at_text,
 	"super" => function Scope::qs_super,
+	"locals" => function Scope::qs_locals,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::types::Number;
+
+	#[test]
+	fn locals() {
+		let parent = Object::new(Scope);
+		parent.set_attr_lit("inherited", Object::from(Number::ONE));
+
+		let scope = Object::new_with_parent(Scope, vec![parent]);
+		scope.set_attr_lit("foo", Object::from(Number::from(1)));
+		scope.set_attr_lit("bar", Object::from(Text::from("two")));
+		scope.set_attr_lit("__args__", Object::from(List::from(Vec::<Object>::new())));
+
+		let locals = Scope::qs_locals(&scope, args!()).unwrap()
+			.as_ref()
+			.iter()
+			.map(|name| name.downcast_ref::<Text>().unwrap().to_string())
+			.collect::<Vec<_>>();
+
+		assert_eq!(locals, vec!["bar".to_string(), "foo".to_string()]);
+	}
 }
\ No newline at end of file
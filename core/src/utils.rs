@@ -1,3 +1,91 @@
+use crate::error::ValueError;
+use crate::types::Number;
+use std::cell::Cell;
+use std::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+/// Hash `value` for use as a Quest `hash`.
+///
+/// This uses a [`DefaultHasher`] with fixed keys, so the hash is consistent across interpreter
+/// runs.
+pub fn stable_hash<T: Hash + ?Sized>(value: &T) -> Number {
+	let mut hasher = DefaultHasher::new();
+	value.hash(&mut hasher);
+
+	Number::from(hasher.finish())
+}
+
+/// The global pool of interned strings.
+#[derive(Default)]
+struct InternPool {
+	strings: HashSet<&'static str>,
+	/// How many of `strings` were added by [`try_intern`]; only these count towards
+	/// [`MAX_INTERNED`].
+	bounded: usize
+}
+
+lazy_static::lazy_static! {
+	static ref INTERNED: RwLock<InternPool> = RwLock::new(InternPool::default());
+}
+
+/// The most strings [`try_intern`] will add to the intern pool; past this, it returns `None` for
+/// strings that aren't already in it.
+///
+/// Strings added by [`intern`] (such as attribute names) don't count towards this, so they can't
+/// crowd identifiers out of the pool.
+pub const MAX_INTERNED: usize = 1 << 12;
+
+fn intern_with(txt: &str, bounded: bool) -> Option<&'static str> {
+	if let Some(&pooled) = INTERNED.read().expect("intern pool poisoned").strings.get(txt) {
+		return Some(pooled);
+	}
+
+	let mut pool = INTERNED.write().expect("intern pool poisoned");
+
+	// another thread may have interned `txt` while we were waiting for the lock.
+	if let Some(&pooled) = pool.strings.get(txt) {
+		return Some(pooled);
+	}
+
+	if bounded {
+		if pool.bounded >= MAX_INTERNED {
+			return None;
+		}
+
+		pool.bounded += 1;
+	}
+
+	// interned strings live for the rest of the program, so just leak them.
+	let pooled: &'static str = Box::leak(txt.to_owned().into_boxed_str());
+	pool.strings.insert(pooled);
+	Some(pooled)
+}
+
+/// Get the pooled copy of `txt`, adding it to the intern pool if it isn't there already.
+///
+/// Unlike [`try_intern`], this always succeeds, so it should only be used for strings that need to
+/// be `'static` (such as attribute names).
+pub fn intern(txt: &str) -> &'static str {
+	intern_with(txt, false).expect("unbounded interning always succeeds")
+}
+
+/// Get the pooled copy of `txt`, adding it to the intern pool only if the pool has fewer than
+/// [`MAX_INTERNED`] strings in it.
+pub fn try_intern(txt: &str) -> Option<&'static str> {
+	intern_with(txt, true)
+}
+
+/// Strip the module path off a type name from [`std::any::type_name`], so that (for example)
+/// `quest_core::types::text::Text` becomes just `Text`.
+pub fn short_type_name(name: &str) -> &str {
+	let end = name.find('<').unwrap_or(name.len());
+	let start = name[..end].rfind("::").map_or(0, |idx| idx + 2);
+
+	&name[start..]
+}
+
 pub fn correct_index(index: isize, len: usize) -> Option<usize> {
 	if !index.is_negative() {
 		if (index as usize) < len {
@@ -13,4 +101,35 @@ pub fn correct_index(index: isize, len: usize) -> Option<usize> {
 			None
 		}
 	}
-}
\ No newline at end of file
+}
+
+thread_local!(
+	static MAX_REPEAT_LEN: Cell<usize> = const { Cell::new(DEFAULT_MAX_REPEAT_LEN) };
+);
+
+/// The default for [`max_repeat_len`].
+pub const DEFAULT_MAX_REPEAT_LEN: usize = 1 << 26;
+
+/// Get the largest collection the current thread can create by repeating another one (such as with
+/// `Text`'s or `List`'s `*`). This is measured in bytes for `Text`, and in elements for `List`.
+#[inline]
+pub fn max_repeat_len() -> usize {
+	MAX_REPEAT_LEN.with(Cell::get)
+}
+
+/// Set the largest collection the current thread can create by repeating another one.
+#[inline]
+pub fn set_max_repeat_len(max_len: usize) {
+	MAX_REPEAT_LEN.with(|max| max.set(max_len))
+}
+
+/// Get the length of repeating something of length `len` a total of `count` times, or a
+/// [`TooLarge`](ValueError::TooLarge) error if that's more than [`max_repeat_len`].
+pub fn repeat_len(len: usize, count: usize) -> Result<usize, ValueError> {
+	let max = max_repeat_len();
+
+	match len.checked_mul(count) {
+		Some(size) if size <= max => Ok(size),
+		size => Err(ValueError::TooLarge { size: size.unwrap_or(usize::MAX), max })
+	}
+}
@@ -1,6 +1,8 @@
 use quest_parser::{Stream, Context, Contexted};
+use quest_core::{Object, Args, Binding};
+use quest_core::types::{Text, Scope};
 use crate::run::Runner;
-use std::io::{self, Seek, SeekFrom};
+use std::io::{self, Seek, SeekFrom, Write, BufRead};
 
 #[derive(Debug, Clone)]
 pub struct Repl {
@@ -8,8 +10,33 @@ pub struct Repl {
 }
 
 impl Runner for Repl {
-	fn run(self) -> crate::Result<quest_core::Object> {
-		unimplemented!()
+	/// Read lines from stdin until it's closed, printing the result of each one.
+	///
+	/// Errors are printed, but don't stop the repl.
+	fn run(mut self) -> crate::Result<Object> {
+		const PROMPT: &str = "> ";
+
+		let stdin = io::stdin();
+		let stdout = io::stdout();
+		let mut lines = stdin.lock().lines();
+
+		loop {
+			{
+				let mut stdout = stdout.lock();
+				write!(stdout, "{}", PROMPT)?;
+				stdout.flush()?;
+			}
+
+			let line =
+				match lines.next() {
+					Some(line) => line?,
+					None => return Ok(Object::default())
+				};
+
+			if let Err(err) = self.handle_line(&line, &mut stdout.lock()) {
+				eprintln!("{}", err);
+			}
+		}
 	}
 }
 
@@ -17,6 +44,44 @@ impl Repl {
 	pub fn new() -> Self {
 		Repl { context: Context::new(Some("<repl>".into())) }
 	}
+
+	/// Handle a single line of input, writing any output to `out`.
+	///
+	/// Lines starting with `:` are meta-commands:
+	/// - `:locals` prints each local variable in the current stackframe alongside its `@text`.
+	/// - `:type <expr>` evaluates `<expr>` and prints its type's name.
+	///
+	/// Everything else is evaluated as Quest code, and the result's `__inspect__` is printed.
+	pub fn handle_line<W: Write>(&mut self, line: &str, out: &mut W) -> crate::Result<()> {
+		self.context.lineno += 1;
+		self.context.line = line.to_string();
+
+		let line = line.trim();
+
+		if line.is_empty() {
+			return Ok(());
+		}
+
+		match line.split_at(line.find(char::is_whitespace).unwrap_or(line.len())) {
+			(":locals", _) => {
+				let binding = Binding::instance();
+
+				for name in Scope::qs_locals(binding.as_ref(), Args::default())? {
+					let value = binding.get_attr(&name)?.downcast_call::<Text>()?;
+					writeln!(out, "{} = {}", name.downcast_call::<Text>()?, value)?;
+				}
+			},
+			(":type", expr) => writeln!(out, "{}", quest_parser::eval(expr)?.short_typename())?,
+			(command, _) if command.starts_with(':') =>
+				writeln!(out, "unknown command: {}", command)?,
+			_ => {
+				let result = quest_parser::eval(line)?;
+				writeln!(out, "{}", result.call_attr_lit("__inspect__", &[])?.downcast_call::<Text>()?)?;
+			}
+		}
+
+		Ok(())
+	}
 }
 impl Iterator for Repl {
 	type Item = quest_parser::Result<char>;
@@ -43,4 +108,56 @@ impl Stream for Repl {
 		// self.read_next_line_if_applicable()?;
 		// Ok(self.as_ref().starts_with(s))
 	}
+
+	fn peek_char(&mut self) -> quest_parser::Result<Option<char>> {
+		unimplemented!()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Feed each line to a fresh repl within its own stackframe, returning everything it printed.
+	fn run_lines(lines: &[&str]) -> crate::Result<String> {
+		quest_parser::init();
+
+		let mut repl = Repl::new();
+		let mut out = vec![];
+		let mut result = Ok(());
+
+		Binding::new_stackframe(Some(Object::new(Scope)), Args::default(), |_| {
+			result = lines.iter().try_for_each(|line| repl.handle_line(line, &mut out));
+			Ok(Object::default())
+		})?;
+
+		result.map(|_| String::from_utf8(out).expect("output isn't utf8"))
+	}
+
+	#[test]
+	fn evaluates_expressions() {
+		assert_eq!(run_lines(&["1 + 2", "", "\"hi\""]).unwrap(), "3\n\"hi\"\n");
+	}
+
+	#[test]
+	fn locals() {
+		let out = run_lines(&["$x = 3", "$name = \"quest\"", ":locals"]).unwrap();
+
+		assert_eq!(out, "3\n\"quest\"\nname = quest\nx = 3\n");
+	}
+
+	#[test]
+	fn r#type() {
+		assert_eq!(run_lines(&[":type 1 + 2"]).unwrap(), "Number\n");
+	}
+
+	#[test]
+	fn unknown_command() {
+		assert_eq!(run_lines(&[":foo bar"]).unwrap(), "unknown command: :foo\n");
+	}
+
+	#[test]
+	fn errors() {
+		assert!(run_lines(&["1 + `"]).is_err());
+	}
 }
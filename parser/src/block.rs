@@ -2,7 +2,7 @@ use quest_core::impl_object_type;
 use quest_core::{Object, Args, Binding};
 
 use crate::Result;
-use crate::token::{Token, ParenType};
+use crate::token::{Token, ParenType, Primative};
 use crate::stream::{Context, Contexted};
 use crate::expression::{Constructable, Expression, PutBack, Executable};
 use std::fmt::{self, Debug, Display, Formatter};
@@ -10,7 +10,14 @@ use std::fmt::{self, Debug, Display, Formatter};
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Line {
 	Single(Expression),
-	Multiple(Vec<Expression>)
+	/// Comma-separated expressions, along with any `name: value` keyword arguments.
+	Multiple(Vec<Expression>, Vec<(String, Expression)>)
+}
+
+impl Line {
+	fn has_keywords(&self) -> bool {
+		matches!(self, Line::Multiple(_, keywords) if !keywords.is_empty())
+	}
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -75,7 +82,7 @@ impl Display for Line {
 		match self {
 			Line::Single(expr) => Display::fmt(expr, f),
 			// OPTIMIZE: I'm sure there's a builtin way to make this easier
-			Line::Multiple(exprs) => {
+			Line::Multiple(exprs, keywords) => {
 				let mut is_first_expr = true;
 				for expr in exprs.iter() {
 					if is_first_expr {
@@ -85,6 +92,14 @@ impl Display for Line {
 					}
 					Display::fmt(&expr, f)?;
 				}
+				for (name, expr) in keywords.iter() {
+					if is_first_expr {
+						is_first_expr = false;
+					} else {
+						write!(f, ", ")?
+					}
+					write!(f, "{}: {}", name, expr)?;
+				}
 				Ok(())
 			}
 		}
@@ -93,23 +108,25 @@ impl Display for Line {
 
 pub enum LineResult {
 	Single(quest_core::Object),
-	Multiple(Vec<quest_core::Object>)
+	Multiple(Vec<quest_core::Object>, Vec<(String, quest_core::Object)>)
 }
 
 impl LineResult {
 	fn force_multiple(self) -> Self {
 		match self {
-			LineResult::Single(obj) => LineResult::Multiple(vec![obj]),
+			LineResult::Single(obj) => LineResult::Multiple(vec![obj], vec![]),
 			multiple => multiple
 		}
 	}
-}
 
-impl From<LineResult> for quest_core::Object {
-	fn from(lr: LineResult) -> Self {
-		match lr {
-			LineResult::Single(obj) => obj,
-			LineResult::Multiple(objs) => objs.into()
+	/// Convert this into an object, which is an error if it has keyword arguments, as those can
+	/// only be passed to calls.
+	fn into_object(self) -> quest_core::Result<quest_core::Object> {
+		match self {
+			LineResult::Single(obj) => Ok(obj),
+			LineResult::Multiple(objs, keywords) if keywords.is_empty() => Ok(objs.into()),
+			LineResult::Multiple(..) => Err(quest_core::error::ValueError::Messaged(
+				"keyword arguments can only be passed to calls".to_string()).into())
 		}
 	}
 }
@@ -119,11 +136,19 @@ impl Line {
 	fn execute(&self) -> quest_core::Result<LineResult> {
 		match self {
 			Line::Single(expr) => expr.execute().map(LineResult::Single),
-			Line::Multiple(exprs) => exprs
-				.iter()
-				.map(Executable::execute)
-				.collect::<quest_core::Result<_>>()
-				.map(LineResult::Multiple)
+			Line::Multiple(exprs, keywords) => {
+				let objs = exprs
+					.iter()
+					.map(Executable::execute)
+					.collect::<quest_core::Result<_>>()?;
+
+				let keywords = keywords
+					.iter()
+					.map(|(name, expr)| Ok((name.clone(), expr.execute()?)))
+					.collect::<quest_core::Result<_>>()?;
+
+				Ok(LineResult::Multiple(objs, keywords))
+			}
 		}
 	}
 }
@@ -147,7 +172,7 @@ impl Block {
 
 			Ok(Some(ret))
 		} else if self.paren_type == ParenType::Square {
-			Ok(Some(LineResult::Multiple(vec![])))
+			Ok(Some(LineResult::Multiple(vec![], vec![])))
 		} else {
 			Ok(None)
 		}
@@ -155,7 +180,7 @@ impl Block {
 
 	fn run_block_to_object(&self) -> quest_core::Result<quest_core::Object> {
 		let lines = self.run_block()?;
-		let lines_obj = lines.map(Object::from).unwrap_or_default();
+		let lines_obj = lines.map(LineResult::into_object).transpose()?.unwrap_or_default();
 		Ok(lines_obj)
 	}
 
@@ -198,6 +223,17 @@ impl Constructable for Block {
 		};
 		let mut curr_line: Option<Line> = None;
 
+		fn push_expr(curr_line: &mut Option<Line>, expr: Expression) {
+			match curr_line.take() {
+				Some(Line::Multiple(mut exprs, keywords)) => {
+					exprs.push(expr);
+					*curr_line = Some(Line::Multiple(exprs, keywords));
+				},
+				Some(Line::Single(first)) => *curr_line = Some(Line::Multiple(vec![first, expr], vec![])),
+				None => *curr_line = Some(Line::Single(expr))
+			}
+		}
+
 		while let Some(tkn) = ctor.next().transpose()? {
 			match tkn {
 				Token::Right(rparen) if rparen == paren => {
@@ -205,6 +241,13 @@ impl Constructable for Block {
 						block.lines.push(curr_line);
 					}
 
+					if let Some((_, rest)) = block.lines.split_last() {
+						if rest.iter().any(Line::has_keywords) {
+							return Err(parse_error!(ctor,
+								Message("keyword arguments must be on the last line of a call")));
+						}
+					}
+
 					return Ok(Some(block))
 				},
 
@@ -215,19 +258,43 @@ impl Constructable for Block {
 					},
 				Token::Comma => 
 					match curr_line {
-						Some(Line::Multiple(_)) => { /* do nothing; commas are used to make `multiple` */},
-						Some(Line::Single(first)) => curr_line = Some(Line::Multiple(vec![first])),
-						None => curr_line = Some(Line::Multiple(vec![]))
+						Some(Line::Multiple(..)) => { /* do nothing; commas are used to make `multiple` */},
+						Some(Line::Single(first)) => curr_line = Some(Line::Multiple(vec![first], vec![])),
+						None => curr_line = Some(Line::Multiple(vec![], vec![]))
 					},
 
+				Token::Primative(Primative::Variable(name)) => {
+					let next = ctor.next().transpose()?;
+
+					if next != Some(Token::Colon) {
+						if let Some(next) = next {
+							ctor.put_back(Ok(next));
+						}
+
+						ctor.put_back(Ok(Token::Primative(Primative::Variable(name))));
+						let expr = Expression::try_construct(ctor)?;
+						push_expr(&mut curr_line, expr);
+						continue;
+					}
+
+					if paren != ParenType::Round {
+						return Err(parse_error!(ctor, Message("keyword arguments are only allowed in calls")));
+					}
+
+					let value = Expression::try_construct(ctor)?;
+					let keyword = (name.to_string(), value);
+
+					match curr_line {
+						Some(Line::Multiple(_, ref mut keywords)) => keywords.push(keyword),
+						Some(Line::Single(first)) => curr_line = Some(Line::Multiple(vec![first], vec![keyword])),
+						None => curr_line = Some(Line::Multiple(vec![], vec![keyword]))
+					}
+				},
+
 				other => {
 					ctor.put_back(Ok(other));
 					let expr = Expression::try_construct(ctor)?;
-					match curr_line {
-						Some(Line::Multiple(ref mut exprs)) => exprs.push(expr),
-						Some(Line::Single(first)) => curr_line = Some(Line::Multiple(vec![first, expr])),
-						None => curr_line = Some(Line::Single(expr))
-					}
+					push_expr(&mut curr_line, expr);
 				}
 			}
 		}
@@ -241,15 +308,10 @@ impl Block {
 	#[inline]
 	pub fn qs_call(this: &Object, args: Args) -> quest_core::Result<Object> {
 		let this_cloned = this.try_downcast_ref::<Block>()?.clone();
-		Binding::new_stackframe(Some(this.clone()), args, move |_| {
-			match this_cloned.run_block_to_object() {
-				Ok(v) => Ok(v),
-				Err(err @ quest_core::Error::Return { .. }) => Err(err),
-				Err(err) => {
-					println!("{:?}", this_cloned.context);
-					Err(err)
-				}
-			}
+		let location = (&this_cloned.context).into();
+
+		Binding::new_stackframe_at(Some(this.clone()), args, Some(location), move |_| {
+			this_cloned.run_block_to_object()
 		})
 
 	// fn call(&self, args: Args) -> quest_core::Result<quest_core::Object> {
@@ -273,11 +335,30 @@ for Block [(parents quest_core::types::Function)]:
 
 #[cfg(test)]
 mod tests {
-	
+	use super::*;
+	use crate::stream::{BufStream, Stream};
+	use quest_core::error::{Error, ValueError};
 
 	#[test]
 	#[ignore]
 	fn call() { todo!(); }
+
+	#[test]
+	fn unbounded_recursion() {
+		crate::init();
+		Binding::set_max_depth(32);
+
+		let expr = Expression::parse_stream(BufStream::from("$f = { f() }; f()").tokens()).unwrap();
+		let err = Binding::new_stackframe(None, Args::default(), |_| expr.execute()).unwrap_err();
+
+		match err {
+			Error::Located { error, .. } =>
+				assert_eq!(*error, ValueError::StackOverflow { max_depth: 32 }.into()),
+			other => panic!("expected a located stack overflow, got {:?}", other)
+		}
+
+		Binding::set_max_depth(Binding::DEFAULT_MAX_DEPTH);
+	}
 }
 
 
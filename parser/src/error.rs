@@ -11,11 +11,14 @@ pub enum ErrorType {
 	UnknownTokenStart(char),
 	UnterminatedQuote,
 	BadEscapeChar(char),
+	BadUnicodeEscape(String),
+	BadCharLiteral(String),
 	UnexpectedToken(Token),
 	Message(&'static str),
 	MessagedString(String),
 	ExpectedExpression,
 	MissingClosingParen(ParenType),
+	StrayBackslash,
 
 	// StreamError(std::io::Error),
 	// Tokenize(TokenizeError),
@@ -35,23 +38,28 @@ impl Error {
 	pub fn new(context: Context, r#type: ErrorType) -> Self {
 		Error { context, r#type }
 	}
+
+	/// Where the error occurred.
+	pub fn context(&self) -> &Context {
+		&self.context
+	}
+
+	/// The kind of error that occurred.
+	pub fn error_type(&self) -> &ErrorType {
+		&self.r#type
+	}
 }
 
 impl Display for Error {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		const TAB_REPLACEMENT: &str = "  ";
-
-		let Context { ref file, lineno, mut column, ref line } = self.context;
+		let Context { ref file, lineno, ref line, tab_width, .. } = self.context;
+		let column = self.context.display_column();
 		let file = file.as_ref()
 			.map(|x| x.to_string_lossy().to_owned().to_string())
 			.unwrap_or_else(|| "<eval>".to_string());
 
-		// replace tabs with a standardized representation for error messages
-		let mut line = line.clone();
-		while let Some(tab_pos) = line.find('\t') {
-			line.replace_range(tab_pos..=tab_pos, TAB_REPLACEMENT);
-			column += TAB_REPLACEMENT.len() - 1;
-		}
+		// expand tabs so the caret lines up with the reported column
+		let line = line.replace('\t', &" ".repeat(tab_width));
 
 		write!(f, concat!("{file}:{lineno}:{column}: parse error, {error}",
 					 "\n    |",
@@ -75,10 +83,14 @@ impl Display for ErrorType {
 			UnknownTokenStart(chr) => write!(f, "unknown token start `{}`", chr),
 			UnterminatedQuote => write!(f, "unterminated quote"),
 			BadEscapeChar(chr) => write!(f, "bad escape char `{}`", chr),
+			BadUnicodeEscape(hex) => write!(f, "bad unicode escape `\\u{{{}`", hex),
+			BadCharLiteral(lit) =>
+				write!(f, "char literal `'{}'` must contain exactly one character", lit),
 			UnterminatedBlockComment => write!(f, "unterminated block comment"),
 			UnexpectedToken(tkn) => write!(f, "unexpected token `{}`", tkn),
 			MissingClosingParen(paren) => write!(f, "missing closing paren `{}`", paren.right()),
 			ExpectedExpression => write!(f, "expected an expression"),
+			StrayBackslash => write!(f, "stray `\\`; a line continuation must be followed by a newline"),
 			Message(msg) => write!(f, "{}", msg),
 			MessagedString(msg) => write!(f, "{}", msg),
 		}
@@ -99,3 +111,59 @@ impl std::error::Error for Error {
 
 
 
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::stream::{BufStream, Stream};
+
+	fn first_error(src: &'static str) -> Error {
+		BufStream::new(std::io::Cursor::new(src), Some("test.qs".into()))
+			.tokens()
+			.find_map(|tkn| tkn.err())
+			.expect("no error was returned")
+	}
+
+	#[test]
+	fn position() {
+		let err = first_error("foo\nbar\n  ` baz");
+
+		assert_eq!(err.context.lineno, 3);
+		assert_eq!(err.context.column, 3);
+		assert!(matches!(err.r#type, ErrorType::UnknownTokenStart('`')));
+	}
+
+	#[test]
+	fn position_after_tab() {
+		let err = first_error("foo\n\t` bar");
+
+		assert_eq!(err.context.lineno, 2);
+		assert_eq!(err.context.column, 2);
+		assert_eq!(err.context.display_column(), Context::DEFAULT_TAB_WIDTH + 1);
+		assert_eq!(
+			quest_core::error::Location::from(&err.context).column,
+			Context::DEFAULT_TAB_WIDTH + 1
+		);
+
+		assert_eq!(
+			err.to_string(),
+			concat!("test.qs:2:5: parse error, unknown token start ```\n",
+			        "    |\n",
+			        " 2  |     ` bar\n",
+			        "    |     ^ here")
+		);
+	}
+
+	#[test]
+	fn display() {
+		let err = first_error("foo\nbar\n  ` baz");
+
+		assert_eq!(
+			err.to_string(),
+			concat!("test.qs:3:3: parse error, unknown token start ```\n",
+			        "    |\n",
+			        " 3  |   ` baz\n",
+			        "    |   ^ here")
+		);
+	}
+}
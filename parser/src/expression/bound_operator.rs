@@ -55,8 +55,13 @@ impl Executable for BoundOperator {
 					return match block.run_block()? {
 						Some(crate::block::LineResult::Single(s)) =>
 							this.call_attr_lit(self.oper.into(), &[&s]),
-						Some(crate::block::LineResult::Multiple(m)) =>
-							this.call_attr_lit(self.oper.into(), m.iter().collect::<Vec<&_>>()),
+						Some(crate::block::LineResult::Multiple(m, keywords)) => {
+							let args = keywords.iter().fold(
+								quest_core::Args::new(m.iter().collect::<Vec<&_>>()),
+								|args, (name, value)| args.with_kwarg(name.as_str(), value));
+
+							this.call_attr_lit(self.oper.into(), args)
+						},
 						None =>
 							this.call_attr_lit(self.oper.into(), &[])
 					},
@@ -110,7 +115,8 @@ impl Constructable for BoundOperator {
 }
 
 
-fn build_op<C>(oper: Operator, ctor: &mut C, mut this: Expression) -> Result<Expression>
+fn build_op<C>(oper: Operator, ctor: &mut C, mut this: Expression, parent_op: Option<Operator>)
+	-> Result<Expression>
 where
 	C: Iterator<Item=Result<Token>> + PutBack + Contexted
 {
@@ -123,15 +129,15 @@ where
 		args: Box::new(OperArgs::Binary(rhs))
 	}.into();
 
-	match ctor.next().transpose()? {
-		Some(Token::Operator(Operator::Assign)) if oper == Operator::Dot => 
-			this = build_op(Operator::DotAssign, ctor, this)?,
-		Some(Token::Operator(next_op)) => this = build_op(next_op, ctor, this)?,
-		Some(tkn) => ctor.put_back(Ok(tkn)),
-		None => {}
+	if oper == Operator::Dot {
+		match ctor.next().transpose()? {
+			Some(Token::Operator(Operator::Assign)) =>
+				return build_op(Operator::DotAssign, ctor, this, parent_op),
+			Some(tkn) => ctor.put_back(Ok(tkn)),
+			None => {}
+		}
 	}
 
-
 	if oper == Operator::DotAssign {
 		// a hack to convert the lhs and rhs into ternary values.
 		this = match this {
@@ -153,7 +159,21 @@ where
 		};
 	}
 
-	Ok(this)
+	// keep absorbing any operators that bind tighter than our parent.
+	BoundOperator::construct_operator(ctor, this, parent_op)
+}
+
+/// Checks to see if `oper` should be grouped before `parent_op` is.
+///
+/// Operators with a lower precedence bind tighter; operators with the same precedence only do so
+/// when they're right-associative (e.g. `2 ** 3 ** 2` is `2 ** (3 ** 2)`).
+fn binds_tighter(oper: Operator, parent_op: Option<Operator>) -> bool {
+	match parent_op {
+		Some(parent_op) if oper.precedence() == parent_op.precedence() =>
+			oper.assoc() == Associativity::RightToLeft,
+		Some(parent_op) => oper < parent_op,
+		None => true
+	}
 }
 
 impl BoundOperator {
@@ -164,8 +184,8 @@ impl BoundOperator {
 	{
 
 		match ctor.next().transpose()? {
-			Some(Token::Operator(oper)) if parent_op.map(|parent_op| oper < parent_op).unwrap_or(true)
-				=> build_op(oper, ctor, lhs),
+			Some(Token::Operator(oper)) if binds_tighter(oper, parent_op)
+				=> build_op(oper, ctor, lhs, parent_op),
 			Some(t @ Token::Operator(_))
 				| Some(t @ Token::Endline)
 				| Some(t @ Token::Comma)
@@ -174,8 +194,8 @@ impl BoundOperator {
 				ctor.put_back(Ok(tkn));
 
 				// any other token indicates that we're being called
-				if parent_op.map(|parent_op| Operator::Call < parent_op).unwrap_or(true) {
-					build_op(Operator::Call, ctor, lhs)
+				if binds_tighter(Operator::Call, parent_op) {
+					build_op(Operator::Call, ctor, lhs, parent_op)
 				} else {
 					ctor.put_back(Ok(Token::Operator(Operator::Call)));
 					Ok(lhs)
@@ -184,4 +204,58 @@ impl BoundOperator {
 			None => Ok(lhs),
 		}
 	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::stream::{BufStream, Stream};
+
+	fn parse_op(code: &str) -> BoundOperator {
+		let mut tokens = BufStream::from_memory(code).tokens();
+		let mut ctor = crate::expression::Constructor::new(&mut tokens);
+
+		match Expression::try_construct(&mut ctor).unwrap() {
+			Expression::Operator(op) => op,
+			other => panic!("expected an operator, got {:?}", other)
+		}
+	}
+
+	fn rhs_op(op: &BoundOperator) -> Option<Operator> {
+		match &*op.args {
+			OperArgs::Binary(Expression::Operator(rhs)) => Some(rhs.oper),
+			_ => None
+		}
+	}
+
+	fn lhs_op(op: &BoundOperator) -> Option<Operator> {
+		match &*op.this {
+			Expression::Operator(lhs) => Some(lhs.oper),
+			_ => None
+		}
+	}
+
+	#[test]
+	fn assign_is_right_associative() {
+		let op = parse_op("a = b = c");
+		assert_eq!(op.oper, Operator::Assign);
+		assert_eq!(lhs_op(&op), None);
+		assert_eq!(rhs_op(&op), Some(Operator::Assign));
 	}
+
+	#[test]
+	fn dot_is_left_associative() {
+		let op = parse_op("a.b.c");
+		assert_eq!(op.oper, Operator::Dot);
+		assert_eq!(lhs_op(&op), Some(Operator::Dot));
+		assert_eq!(rhs_op(&op), None);
+	}
+
+	#[test]
+	fn call_binds_tighter_than_binary_operators() {
+		let op = parse_op("a.b 3 + 4");
+		assert_eq!(op.oper, Operator::Add);
+		assert_eq!(lhs_op(&op), Some(Operator::Call));
+	}
+}
+
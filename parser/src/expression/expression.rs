@@ -95,6 +95,14 @@ impl Expression {
 }
 
 impl Expression {
+	/// Parse an entire expression out of `iter`, leaving it exhausted.
+	pub fn try_from_iter<I>(iter: &mut I) -> Result<Self>
+	where
+		I: Iterator<Item=Result<Token>> + Contexted
+	{
+		Expression::parse_stream(iter)
+	}
+
 	pub fn parse_stream<I>(iter: I) -> Result<Self>
 	where
 		I: Iterator<Item=Result<Token>> + Contexted
@@ -143,4 +151,42 @@ impl Expression {
 	}
 }
 
-	
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::stream::{BufStream, Stream};
+
+	fn parse(code: &str) -> String {
+		let mut tokens = BufStream::from_memory(code).tokens();
+		let expr = Expression::try_from_iter(&mut tokens).unwrap().to_string();
+
+		// strip the round block that wraps the whole stream
+		expr.strip_prefix("( ").and_then(|e| e.strip_suffix(" )")).unwrap().to_string()
+	}
+
+	#[test]
+	fn precedence() {
+		assert_eq!(parse("1 + 2 * 3"), "(1) + ((2) * (3))");
+		assert_eq!(parse("1 * 2 + 3"), "((1) * (2)) + (3)");
+		assert_eq!(parse("1 == 2 * 3 + 4"), "(1) == (((2) * (3)) + (4))");
+		assert_eq!(parse("(1 + 2) * 3"), "(( (1) + (2) )) * (3)");
+	}
+
+	#[test]
+	fn left_associative() {
+		assert_eq!(parse("1 - 2 * 3 - 4"), "((1) - ((2) * (3))) - (4)");
+		assert_eq!(parse("1 - 2 - 3"), "((1) - (2)) - (3)");
+		assert_eq!(parse("a.b.c"), "a.b.c");
+	}
+
+	#[test]
+	fn right_associative() {
+		assert_eq!(parse("2 ** 3 ** 2"), "(2) ** ((3) ** (2))");
+		assert_eq!(parse("a = 1 + 2"), "a = (1) + (2)");
+	}
+
+	#[test]
+	fn dot_assign() {
+		assert_eq!(parse("a.b = 1 + 2"), "a.b = (1) + (2)");
+	}
+}
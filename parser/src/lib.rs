@@ -6,8 +6,21 @@
 
 /// Setup the quest parser. This should be run before anything within `quest_parser` is used.
 pub fn init() {
-	use quest_core::types::ObjectType;
-	quest_core::types::Kernel::mapping().set_attr_lit("Block", Block::mapping());
+	use quest_core::types::{ObjectType, Kernel};
+	Kernel::mapping().set_attr_lit("Block", Block::mapping());
+	Kernel::set_evaluator(eval);
+}
+
+/// Parse and execute `src` within the current [`Binding`](quest_core::Binding).
+///
+/// This is what `Kernel`'s `eval` uses once [`init`] has been called. Parse errors are returned as
+/// [`Boxed`](quest_core::Error::Boxed) errors.
+pub fn eval(src: &str) -> quest_core::Result<quest_core::Object> {
+	use expression::Executable;
+
+	Expression::parse_stream(stream::BufStream::from_memory(src).tokens())
+		.map_err(|err| quest_core::Error::Boxed(Box::new(err)))?
+		.execute()
 }
 
 #[macro_use]
@@ -24,3 +37,92 @@ pub use error::{Error, ErrorType, Result};
 pub use token::Token;
 pub use expression::Expression;
 pub use stream::{Stream, Context, Contexted};
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use quest_core::{Binding, Args, Object, types::{Number, Text, Kernel}};
+
+	fn as_number(obj: Object) -> Number {
+		*obj.downcast_ref::<Number>().expect("not a number")
+	}
+
+	#[test]
+	fn eval_expression() {
+		init();
+
+		let result = Binding::new_stackframe(None, Args::default(), |_| eval("1 + 2")).unwrap();
+		assert_eq!(as_number(result), Number::from(3));
+	}
+
+	#[test]
+	fn if_calls_blocks_but_not_values() {
+		init();
+
+		let run = |src| Binding::new_stackframe(None, Args::default(), |_| eval(src)).unwrap();
+
+		assert_eq!(as_number(run("if(true, { 1 + 2 }, 4)")), Number::from(3));
+		assert_eq!(as_number(run("if(true, 3)")), Number::from(3));
+		assert_eq!(
+			run("if(false, \"yes\", \"no\")").downcast_call::<quest_core::types::Text>().unwrap(),
+			quest_core::types::Text::from("no")
+		);
+	}
+
+	#[test]
+	fn keyword_arguments() {
+		use quest_core::types::{Basic, RustFn};
+		init();
+
+		let scale = Object::from(RustFn::new("scale", |_, args| {
+			let default = Object::from(1);
+			args.arg(0)?.call_attr_lit("*", &[args.kwarg_or("by", &default)])
+		}));
+
+		let run = |src| Binding::new_stackframe(None, Args::default(), |binding| {
+			let obj = Object::new(Basic);
+			obj.set_attr_lit("scale", scale.clone());
+			binding.set_attr_lit("obj", obj);
+			binding.set_attr_lit("scale", scale.clone());
+			eval(src)
+		});
+
+		assert_eq!(as_number(run("scale(3, by: 2)").unwrap()), Number::from(6));
+		assert_eq!(as_number(run("scale(3)").unwrap()), Number::from(3));
+		assert_eq!(as_number(run("obj.$scale(3, by: 4)").unwrap()), Number::from(12));
+
+		// blocks get them as local variables
+		assert_eq!(as_number(run("$f = { _1 + by }; f(1, by: 2)").unwrap()), Number::from(3));
+
+		assert!(run("(by: 2)").is_err());
+		assert!(matches!(run("[by: 2]").unwrap_err(), quest_core::Error::Boxed(_)));
+		assert!(matches!(run("scale(by: 2; 3)").unwrap_err(), quest_core::Error::Boxed(_)));
+	}
+
+	#[test]
+	fn eval_sees_locals() {
+		init();
+
+		let result = Binding::new_stackframe(None, Args::default(), |binding| {
+			binding.set_attr_lit("x", Object::from(5));
+			Kernel::eval("x * 2")
+		}).unwrap();
+
+		assert_eq!(as_number(result), Number::from(10));
+	}
+
+	#[test]
+	fn eval_errors() {
+		init();
+
+		let parse_error = Binding::new_stackframe(None, Args::default(), |_| eval("1 + `"))
+			.unwrap_err();
+		assert!(matches!(parse_error, quest_core::Error::Boxed(_)));
+
+		let src = Object::from(Text::from("1 + undefined_variable"));
+		let runtime_error = Binding::new_stackframe(None, Args::default(), |_| {
+			Kernel::qs_eval(&Kernel.into(), Args::from(&[&src][..]))
+		});
+		assert!(runtime_error.is_err());
+	}
+}
@@ -73,9 +73,18 @@ impl<B: BufRead> Contexted for BufStream<B> {
 }
 
 impl<B: BufRead> Stream for BufStream<B> {
+	/// Check to see if the rest of the current line starts with `s`, without consuming anything.
+	///
+	/// If we're at the end of the current line, the next one is read in first. Because only one
+	/// line is ever buffered at a time, `s` will never match across a `\n`.
 	fn starts_with(&mut self, s: &str) -> Result<bool> {
 		self.line().map(|line| line.starts_with(s))
 	}
+
+	/// Get the next character from the current line without moving the cursor.
+	fn peek_char(&mut self) -> Result<Option<char>> {
+		self.line().map(|line| line.chars().next())
+	}
 }
 
 impl<B: BufRead> BufStream<B> {
@@ -141,6 +150,19 @@ impl<T: AsRef<[u8]>> From<T> for BufStream<Cursor<T>> {
 	}
 }
 
+impl<T: AsRef<[u8]>> BufStream<Cursor<T>> {
+	/// The name given to the [`Context`](#) of streams created via [`from_memory`](#).
+	pub const MEMORY_CONTEXT: &'static str = "<string>";
+
+	/// Create a new [`BufStream`](#) for data that's held in memory, such as a line read by a REPL.
+	///
+	/// This is identical to [`BufStream::from`](#), except the context is given the name
+	/// [`MEMORY_CONTEXT`](#) so error messages can distinguish it from other sources.
+	pub fn from_memory(data: T) -> Self {
+		BufStream::new(Cursor::new(data), Some(Self::MEMORY_CONTEXT.into()))
+	}
+}
+
 impl TryFrom<&'_ Path> for BufStream<BufReader<File>> {
 	type Error = io::Error;
 
@@ -187,6 +209,28 @@ mod tests {
 		let _: BufStream<_> = BufStream::from("plato's dialogues");
 	} 
 
+	#[test]
+	fn from_memory() {
+		use crate::token::{Token, Primative};
+
+		let mut buf = BufStream::from_memory(r#""the crito" apology"#);
+		assert_eq!(buf.context().file, Some(BufStream::<Cursor<&str>>::MEMORY_CONTEXT.into()));
+
+		assert_start_with!(buf, r#""the crito""#);
+		assert_next_eq!(buf, '"');
+		assert_start_with!(buf, r#"the crito""#);
+
+		buf.seek(SeekFrom::Start(0)).expect("can't seek");
+
+		let mut tokens = buf.tokens();
+		assert_eq!(
+			tokens.next().unwrap().unwrap(),
+			Token::Primative(Primative::Text("the crito".into()))
+		);
+		assert_eq!(tokens.next().unwrap().unwrap().to_string(), "apology");
+		assert!(tokens.next().is_none());
+	}
+
 	#[test]
 	fn from_path() {
 		let tmpfile = tempfile::NamedTempFile::new().expect("couldn't make tempfile");
@@ -201,6 +245,51 @@ mod tests {
 		let _: BufStream<_> = BufStream::stdin();
 	}
 
+	#[test]
+	fn starts_with() {
+		let mut buf = BufStream::from("meno\nphaedo");
+
+		assert!(buf.starts_with("").unwrap());
+		assert!(buf.starts_with("me").unwrap());
+		assert!(buf.starts_with("meno\n").unwrap());
+		assert!(!buf.starts_with("phaedo").unwrap());
+		assert!(!buf.starts_with("menos").unwrap());
+
+		// make sure nothing was consumed
+		assert_next_eq!(buf, 'm');
+		assert!(buf.starts_with("eno").unwrap());
+	}
+
+	#[test]
+	fn starts_with_longer_than_line() {
+		let mut buf = BufStream::from("ion\ncratylus");
+
+		// needles never span lines, even if the next line would match
+		assert!(!buf.starts_with("ion\ncratylus").unwrap());
+		assert!(!buf.starts_with("ion\ncratylus and more").unwrap());
+		assert!(buf.starts_with("ion\n").unwrap());
+
+		buf.seek(SeekFrom::End(0)).expect("can't seek");
+		assert!(!buf.starts_with("cratylus and more").unwrap());
+		assert!(buf.starts_with("cratylus").unwrap());
+	}
+
+	#[test]
+	fn starts_with_refills() {
+		let mut buf = BufStream::from("lysis\nlaches");
+		assert!(buf.starts_with("lysis").unwrap());
+
+		buf.seek(SeekFrom::End(0)).expect("can't seek");
+		assert!(buf.starts_with("laches").unwrap());
+		assert_eq!(buf.context().lineno, 2);
+		assert_next_eq!(buf, 'l');
+
+		buf.seek(SeekFrom::End(0)).expect("can't seek");
+		assert!(!buf.starts_with("laches").unwrap());
+		assert!(buf.starts_with("").unwrap());
+		assert_next_eq!(buf, None);
+	}
+
 	#[test]
 	#[ignore]
 	fn read_next_line_if_applicable() {
@@ -250,7 +339,10 @@ mod tests {
 				assert_next_eq!(buf, $chr);
 				assert_eq!(
 					*buf.context(),
-					Context { file: None, lineno: $lineno, column: $column, line: $line.into() }
+					Context {
+						file: None, lineno: $lineno, column: $column, line: $line.into(),
+						..Context::default()
+					}
 				);
 			};
 		}
@@ -273,7 +365,9 @@ mod tests {
 		assert_next_eq!(buf, None);
 		assert_eq!(
 			*buf.context(),
-			Context { file: None, lineno: 4, column: 7, line: "apology".to_string() }
+			Context {
+				file: None, lineno: 4, column: 7, line: "apology".to_string(), ..Context::default()
+			}
 		);
 
 		Ok(())
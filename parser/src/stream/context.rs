@@ -8,8 +8,15 @@ pub trait Contexted {
 	fn context(&self) -> &Context;
 }
 
+impl<C: Contexted + ?Sized> Contexted for &'_ mut C {
+	#[inline]
+	fn context(&self) -> &Context {
+		(**self).context()
+	}
+}
+
 /// A type representing the current state of a [`Stream`](trait.Stream.html).
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Context {
 	/// The file, if one exists, that is associated with this context
 	pub file: Option<PathBuf>,
@@ -18,14 +25,49 @@ pub struct Context {
 	/// The column within [`line`](#structfield.line) that's being parsed.
 	pub column: usize,
 	/// The current line that is being parsed
-	pub line: String
+	pub line: String,
+	/// How many columns a `\t` takes up when reporting positions.
+	pub tab_width: usize
+}
+
+impl Default for Context {
+	fn default() -> Self {
+		Context {
+			file: None,
+			lineno: 0,
+			column: 0,
+			line: String::default(),
+			tab_width: Context::DEFAULT_TAB_WIDTH
+		}
+	}
 }
 
 impl Context {
+	/// The [`tab_width`](#structfield.tab_width) a new context is given.
+	pub const DEFAULT_TAB_WIDTH: usize = 4;
+
 	/// Create a new context
 	pub fn new(file: Option<PathBuf>) -> Self {
 		Context { file, ..Context::default() }
 	}
+
+	/// The [`column`](#structfield.column), as it would appear in an editor.
+	///
+	/// `column` itself counts characters, as streams use it to seek within the
+	/// [`line`](#structfield.line); this instead advances by [`tab_width`](#structfield.tab_width)
+	/// for each `\t` before the column.
+	pub fn display_column(&self) -> usize {
+		self.line.chars()
+			.take(self.column)
+			.map(|chr| if chr == '\t' { self.tab_width } else { 1 })
+			.sum()
+	}
+}
+
+impl From<&'_ Context> for quest_core::error::Location {
+	fn from(context: &Context) -> Self {
+		quest_core::error::Location::new(context.file.clone(), context.lineno, context.display_column())
+	}
 }
 
 impl<T: Into<PathBuf>> From<T> for Context {
@@ -43,7 +85,7 @@ mod tests {
 	fn default() {
 		assert_eq!(
 			Context::default(),
-			Context { file: None, lineno: 0, column: 0, line: String::default() }
+			Context { file: None, lineno: 0, column: 0, line: String::default(), tab_width: 4 }
 		);
 	}
 
@@ -62,4 +104,36 @@ mod tests {
 			Context::from("/plato/meno.txt"),
 			Context { file: Some("/plato/meno.txt".into()), ..Context::default() });
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn into_location() {
+		let context = Context {
+			file: Some("/plato/meno.txt".into()),
+			lineno: 3,
+			column: 4,
+			line: "virtue".into(),
+			..Context::default()
+		};
+
+		assert_eq!(
+			quest_core::error::Location::from(&context),
+			quest_core::error::Location::new(Some("/plato/meno.txt".into()), 3, 4)
+		);
+	}
+
+	#[test]
+	fn display_column() {
+		let mut context = Context { line: "\tab\t`".into(), column: 5, ..Context::default() };
+		assert_eq!(context.display_column(), 11);
+
+		context.tab_width = 2;
+		assert_eq!(context.display_column(), 7);
+
+		context.column = 1;
+		assert_eq!(context.display_column(), 2);
+
+		context.line = "no tabs".into();
+		context.column = 3;
+		assert_eq!(context.display_column(), 3);
+	}
+}
@@ -0,0 +1,82 @@
+use crate::{Result, Stream, Context, Contexted};
+
+/// Converts a [`Stream`] into an iterator over its lines.
+///
+/// This is created by the [`Stream::lines()`] method. Each line is yielded without its line
+/// ending, and both `\n` and `\r\n` are recognized as line endings. The final line is yielded even
+/// if it doesn't end with a newline.
+///
+/// [`Stream`]: trait.Stream.html
+/// [`Stream::lines()`]: trait.Stream.html#method.lines
+#[derive(Debug)]
+pub struct Lines<S: Stream>(pub(super) S);
+
+impl<S: Stream> Lines<S> {
+	pub(super) fn new(stream: S) -> Self {
+		Lines(stream)
+	}
+}
+
+impl<S: Stream> Iterator for Lines<S> {
+	type Item = Result<String>;
+
+	/// Reads characters until the next line ending (or the end of the stream).
+	fn next(&mut self) -> Option<Result<String>> {
+		let mut line = String::new();
+
+		loop {
+			match self.0.next() {
+				Some(Ok('\n')) => break,
+				Some(Ok(chr)) => line.push(chr),
+				Some(Err(err)) => return Some(Err(err)),
+				None if line.is_empty() => return None,
+				None => break
+			}
+		}
+
+		if line.ends_with('\r') {
+			line.pop();
+		}
+
+		Some(Ok(line))
+	}
+}
+
+impl<S: Stream> Contexted for Lines<S> {
+	/// Returns the context of the underlying type.
+	fn context(&self) -> &Context {
+		self.0.context()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::stream::{BufStream, Stream};
+
+	fn lines(input: &str) -> Vec<String> {
+		BufStream::from(input).lines().collect::<crate::Result<_>>().expect("couldn't read lines")
+	}
+
+	#[test]
+	fn trailing_newline() {
+		assert_eq!(lines("euthyphro\napology\ncrito\n"), ["euthyphro", "apology", "crito"]);
+	}
+
+	#[test]
+	fn no_trailing_newline() {
+		assert_eq!(lines("euthyphro\napology\ncrito"), ["euthyphro", "apology", "crito"]);
+	}
+
+	#[test]
+	fn crlf() {
+		assert_eq!(lines("phaedo\r\nmeno\r\n"), ["phaedo", "meno"]);
+		assert_eq!(lines("phaedo\r\nmeno"), ["phaedo", "meno"]);
+	}
+
+	#[test]
+	fn blank_lines() {
+		assert_eq!(lines("gorgias\n\n\nprotagoras"), ["gorgias", "", "", "protagoras"]);
+		assert_eq!(lines("\n"), [""]);
+		assert!(lines("").is_empty());
+	}
+}
@@ -1,11 +1,22 @@
 //! Traits and types for interacting with streams.
 mod context;
 mod token_iter;
+mod lines;
 mod buf_stream;
 
 use std::io::Seek;
 use crate::Result;
 
+/// A position within a [`Stream`]'s current line, which can be returned to later.
+///
+/// See [`Stream::save_position`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+	lineno: usize,
+	column: u64,
+	at_line_end: bool
+}
+
 /// A trait representing something that can be used to parse [`Token`](#)s from.
 ///
 /// We're guaranteed not to try and `seek` before the beginning of a line; whenever a new line is
@@ -29,12 +40,164 @@ pub trait Stream : Seek + Contexted + Iterator<Item=Result<char>> {
 		}
 	}
 
+	/// Get the next character without consuming it, or `None` at the end of the stream.
+	///
+	/// If we're at the end of a line, this reads the next one, which means positions
+	/// [saved](Stream::save_position) in the middle of the old line can't be restored afterwards.
+	fn peek_char(&mut self) -> Result<Option<char>>;
+
+	/// Save the current position so it can be [restored](Stream::restore_position) later.
+	///
+	/// Since a new line is a new stream source, a position can only be restored while still on
+	/// the line it was saved on. The one exception is a position saved at the very end of a line,
+	/// which is the same as the start of the next one.
+	fn save_position(&mut self) -> Result<Position> {
+		let column = self.stream_position()
+			.map_err(|err| parse_error!(self, CantReadStream(err)))?;
+		let context = self.context();
+
+		Ok(Position {
+			lineno: context.lineno,
+			column,
+			at_line_end: context.column >= context.line.chars().count()
+		})
+	}
+
+	/// Return to a position previously [saved](Stream::save_position).
+	///
+	/// # Errors
+	/// If the stream has moved onto another line since `pos` was saved.
+	fn restore_position(&mut self, pos: Position) -> Result<()> {
+		let lineno = self.context().lineno;
+
+		if lineno == pos.lineno {
+			try_seek!(self, Start(pos.column));
+		} else if pos.at_line_end && lineno == pos.lineno + 1 {
+			try_seek!(self, Start(0));
+		} else {
+			return Err(parse_error!(self, Message("can't restore a position from a different line")));
+		}
+
+		Ok(())
+	}
+
+	/// Attempt to parse something with `func`, rewinding back to where we started if it returns
+	/// `Ok(None)`.
+	///
+	/// Errors are returned as-is, without rewinding.
+	fn with_rewind<T, F>(&mut self, func: F) -> Result<Option<T>>
+	where
+		Self: Sized,
+		F: FnOnce(&mut Self) -> Result<Option<T>>
+	{
+		let pos = self.save_position()?;
+
+		match func(self)? {
+			Some(value) => Ok(Some(value)),
+			None => self.restore_position(pos).and(Ok(None))
+		}
+	}
+
 	/// Converts this stream into an iterator over tokens.
 	fn tokens(self) -> TokenIter<Self> where Self: Sized {
-		TokenIter(self)
+		TokenIter::new(self)
+	}
+
+	/// Converts this stream into an iterator over its lines, without tokenizing them.
+	///
+	/// Because a new line is always a new stream source, this never needs to seek backwards, and
+	/// so is a cheap way to pre-scan a source.
+	fn lines(self) -> Lines<Self> where Self: Sized {
+		Lines::new(self)
 	}
 }
 
 pub use context::{Context, Contexted};
 pub use token_iter::TokenIter;
-pub use buf_stream::BufStream;
\ No newline at end of file
+pub use lines::Lines;
+pub use buf_stream::BufStream;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::token::{Token, Operator};
+	use crate::token::Primative;
+
+	#[test]
+	fn peek_char() {
+		let mut stream = BufStream::from("ab\nc");
+
+		for &chr in &['a', 'b', '\n', 'c'] {
+			assert_eq!(stream.peek_char().unwrap(), Some(chr));
+			assert_eq!(stream.peek_char().unwrap(), Some(chr));
+			assert_eq!(stream.next().transpose().unwrap(), Some(chr));
+		}
+
+		assert_eq!(stream.context().lineno, 2);
+		assert_eq!(stream.peek_char().unwrap(), None);
+		assert_eq!(stream.next().transpose().unwrap(), None);
+	}
+
+	#[test]
+	fn peek_char_doesnt_move() {
+		let mut stream = BufStream::from("ab");
+
+		assert_eq!(stream.next().transpose().unwrap(), Some('a'));
+		let pos = stream.save_position().unwrap();
+
+		assert_eq!(stream.peek_char().unwrap(), Some('b'));
+		assert_eq!(stream.save_position().unwrap(), pos);
+	}
+
+	#[test]
+	fn with_rewind() {
+		let mut stream = BufStream::from("foo.bar");
+
+		let op = stream.with_rewind(|stream| match Token::try_parse(stream)? {
+			Some(Token::Operator(op)) => Ok(Some(op)),
+			_ => Ok(None)
+		}).unwrap();
+
+		assert_eq!(op, None);
+		assert!(matches!(
+			Token::try_parse(&mut stream).unwrap(),
+			Some(Token::Primative(Primative::Variable(var))) if var.to_string() == "foo"
+		));
+
+		let op = stream.with_rewind(|stream| match Token::try_parse(stream)? {
+			Some(Token::Operator(op)) => Ok(Some(op)),
+			_ => Ok(None)
+		}).unwrap();
+
+		assert_eq!(op, Some(Operator::Dot));
+	}
+
+	#[test]
+	fn save_and_restore() {
+		let mut stream = BufStream::from("abc");
+
+		assert_eq!(stream.next().transpose().unwrap(), Some('a'));
+		let pos = stream.save_position().unwrap();
+
+		assert!(stream.next_if_starts_with("bc").unwrap());
+		stream.restore_position(pos).unwrap();
+		assert!(stream.next_if_starts_with("b").unwrap());
+	}
+
+	#[test]
+	fn restore_on_another_line() {
+		let mut stream = BufStream::from("a\nbc");
+
+		assert_eq!(stream.next().transpose().unwrap(), Some('a'));
+		let mid_line = stream.save_position().unwrap();
+		assert_eq!(stream.next().transpose().unwrap(), Some('\n'));
+		let line_end = stream.save_position().unwrap();
+
+		assert_eq!(stream.next().transpose().unwrap(), Some('b'));
+		assert!(stream.restore_position(mid_line).is_err());
+
+		// the end of a line is the start of the next one
+		stream.restore_position(line_end).unwrap();
+		assert_eq!(stream.next().transpose().unwrap(), Some('b'));
+	}
+}
@@ -1,4 +1,5 @@
 use crate::{Result, Stream, Token, Context, Contexted};
+use std::collections::VecDeque;
 
 /// Converts a [`Stream`] into an iterator over tokens.
 ///
@@ -7,17 +8,62 @@ use crate::{Result, Stream, Token, Context, Contexted};
 /// This type exists because a type implementing [`Stream`] must already implement the `Iterator`
 /// trait for `Result<char>`, and thus can't also implement it for `Result<Token>`.
 ///
+/// Tokens that have been [peeked](#method.peek) are buffered, and since parse errors can't be
+/// cloned or compared, this only derives `Debug` and `Default`.
+///
 /// [`Stream`]: trait.Stream.html
 /// [`Stream::tokens()`]: trait.Stream.html#method.tokens
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
-pub struct TokenIter<S: Stream>(pub(super) S);
+#[derive(Debug, Default)]
+pub struct TokenIter<S: Stream>(pub(super) S, VecDeque<Result<Token>>);
+
+impl<S: Stream> TokenIter<S> {
+	pub(super) fn new(stream: S) -> Self {
+		TokenIter(stream, VecDeque::new())
+	}
+
+	/// Ensure at least `amnt` tokens are buffered, returning `false` if the stream ran out first.
+	fn fill_peeked(&mut self, amnt: usize) -> bool {
+		while self.1.len() < amnt {
+			match Token::try_parse(&mut self.0).transpose() {
+				Some(tkn) => self.1.push_back(tkn),
+				None => return false
+			}
+		}
+
+		true
+	}
+
+	/// Look at the next token without consuming it.
+	///
+	/// The token is buffered, and will be returned by the next call to `next`. Note that the
+	/// [`context`](#method.context) reflects the underlying stream, and so is already past any
+	/// peeked tokens.
+	pub fn peek(&mut self) -> Option<&Result<Token>> {
+		if self.fill_peeked(1) {
+			self.1.front()
+		} else {
+			None
+		}
+	}
+
+	/// Look at the token after the next one without consuming either.
+	///
+	/// See [`peek`](#method.peek) for more details.
+	pub fn peek2(&mut self) -> Option<&Result<Token>> {
+		if self.fill_peeked(2) {
+			self.1.get(1)
+		} else {
+			None
+		}
+	}
+}
 
 impl<S: Stream> Iterator for TokenIter<S> {
 	type Item = Result<Token>;
 
 	/// Returns a token parsed by [`Token`](../token/enum.Token.html)
 	fn next(&mut self) -> Option<Result<Token>> {
-		Token::try_parse(&mut self.0).transpose()
+		self.1.pop_front().or_else(|| Token::try_parse(&mut self.0).transpose())
 	}
 }
 
@@ -42,5 +88,45 @@ mod tests {
 		assert_eq!(iter.next().unwrap().unwrap().to_string(), "there");
 		assert_eq!(iter.context(), iter.0.context());
 	}
-}
 
+	#[test]
+	fn peek() {
+		let mut iter = BufStream::from("who goes there").tokens();
+
+		assert_eq!(iter.peek().unwrap().as_ref().unwrap().to_string(), "who");
+		assert_eq!(iter.peek().unwrap().as_ref().unwrap().to_string(), "who");
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "who");
+
+		assert_eq!(iter.peek().unwrap().as_ref().unwrap().to_string(), "goes");
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "goes");
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "there");
+
+		assert!(iter.peek().is_none());
+		assert!(iter.next().is_none());
+	}
+
+	#[test]
+	fn peek2() {
+		let mut iter = BufStream::from("who goes there").tokens();
+
+		assert_eq!(iter.peek2().unwrap().as_ref().unwrap().to_string(), "goes");
+		assert_eq!(iter.peek().unwrap().as_ref().unwrap().to_string(), "who");
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "who");
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "goes");
+
+		assert!(iter.peek2().is_none());
+		assert_eq!(iter.peek().unwrap().as_ref().unwrap().to_string(), "there");
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "there");
+		assert!(iter.next().is_none());
+	}
+
+	#[test]
+	fn peek_error() {
+		let mut iter = BufStream::from("a ` b").tokens();
+
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "a");
+		assert!(iter.peek().unwrap().is_err());
+		assert!(iter.next().unwrap().is_err());
+		assert_eq!(iter.next().unwrap().unwrap().to_string(), "b");
+	}
+}
@@ -19,13 +19,14 @@ fn line_comment<S: Stream>(stream: &mut S) -> Result<()> {
 fn block_comment<S: Stream>(stream: &mut S) -> Result<()> {
 	let begin_context = stream.context().clone();
 
-	while let Some(chr) = stream.next().transpose()? {
-		match chr {
-			// end of line
-			'*' if stream.next().transpose()? == Some('/') => return Ok(()),
+	loop {
+		if stream.next_if_starts_with("*/")? {
+			return Ok(());
+		} else if stream.next_if_starts_with("/*")? {
 			// allow for nested block comments
-			'/' if stream.next().transpose()? == Some('*') => block_comment(stream)?,
-			_ => { /* do nothing, we ignore other characters */ }
+			block_comment(stream)?;
+		} else if stream.next().transpose()?.is_none() {
+			break;
 		}
 	}
 
@@ -46,4 +47,61 @@ impl Tokenizable for Comment {
 			Ok(TokenizeResult::None)
 		}
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::ErrorType;
+	use crate::stream::{BufStream, Stream};
+
+	fn tokenize(src: &'static str) -> crate::Result<Vec<String>> {
+		BufStream::from(src).tokens()
+			.map(|tkn| tkn.map(|tkn| tkn.to_string()))
+			.collect()
+	}
+
+	#[test]
+	fn line_comment() {
+		assert_eq!(tokenize("a # b c").unwrap(), vec!["a"]);
+		assert_eq!(tokenize("a # b\nc").unwrap(), vec!["a", "c"]);
+		assert_eq!(tokenize("# just a comment").unwrap(), Vec::<String>::new());
+		assert_eq!(tokenize("#\n#\na").unwrap(), vec!["a"]);
+	}
+
+	#[test]
+	fn block_comment() {
+		assert_eq!(tokenize("a /* b */ c").unwrap(), vec!["a", "c"]);
+		assert_eq!(tokenize("a/*b*/c").unwrap(), vec!["a", "c"]);
+		assert_eq!(tokenize("a /* b\nc\n */ d").unwrap(), vec!["a", "d"]);
+		assert_eq!(tokenize("a /* b **/ c").unwrap(), vec!["a", "c"]);
+		assert_eq!(tokenize("a /* # b */ c").unwrap(), vec!["a", "c"]);
+	}
+
+	#[test]
+	fn nested_block_comment() {
+		assert_eq!(tokenize("a /* b /* c */ d */ e").unwrap(), vec!["a", "e"]);
+		assert_eq!(tokenize("a /* /* /* */ */ */ b").unwrap(), vec!["a", "b"]);
+	}
+
+	#[test]
+	fn unterminated_block_comment() {
+		macro_rules! assert_unterminated {
+			($src:literal) => {
+				match tokenize($src) {
+					Err(err) => assert!(matches!(err.error_type(), ErrorType::UnterminatedBlockComment),
+						"wrong error for {:?}: {:?}", $src, err),
+					Ok(tkns) => panic!("no error for {:?}: {:?}", $src, tkns)
+				}
+			};
+		}
+
+		assert_unterminated!("a /* b");
+		assert_unterminated!("a /* b /* c */ d");
+		assert_unterminated!("a /* b *");
+	}
+
+	#[test]
+	fn eof_marker() {
+		assert_eq!(tokenize("a\n##__EOF__##\nb").unwrap(), vec!["a"]);
+	}
+}
@@ -40,14 +40,26 @@ macro_rules! operator_enum {
 		impl Tokenizable for Operator {
 			type Item = Self;
 
+			/// Tokenize the longest operator the stream starts with ("maximal munch"), so that, eg,
+			/// `<=>` is always a single operator, and never `<=` followed by `>`.
 			fn try_tokenize<S: Stream>(stream: &mut S) -> Result<TokenizeResult<Self::Item>> {
-				$({
-					let o = operator_enum!(; TRY_PARSE $repr $(($($ident)?))?);
-					if o.map(|x| stream.next_if_starts_with(x)).transpose()?.unwrap_or(false) {
-						return Ok(TokenizeResult::Some(Operator::$variant))
+				let mut longest: Option<(Operator, &'static str)> = None;
+
+				$(
+					let repr: Option<&'static str> = operator_enum!(; TRY_PARSE $repr $(($($ident)?))?);
+					if let Some(repr) = repr {
+						if longest.map_or(true, |(_, prev)| prev.len() < repr.len())
+							&& stream.starts_with(repr)?
+						{
+							longest = Some((Operator::$variant, repr));
+						}
 					}
-				})+
-				{
+				)+
+
+				if let Some((oper, repr)) = longest {
+					try_seek!(stream, repr.len() as _);
+					Ok(TokenizeResult::Some(oper))
+				} else {
 					Ok(TokenizeResult::None)
 				}
 			}
@@ -56,6 +68,29 @@ macro_rules! operator_enum {
 		impl Operator {
 			pub const MAX_PRECEDENCE: usize = operator_enum!(; MAX_PRECEDENCE $($ord)+) as usize;
 
+			/// Every operator, alongside its precedence.
+			///
+			/// Lower precedences bind tighter. This contains the operators that can't be written
+			/// directly in source code (such as [`Neg`](#variant.Neg)) too.
+			pub const PRECEDENCE_TABLE: &'static [(Operator, usize)] = &[
+				$((Operator::$variant, $ord)),+
+			];
+
+			/// Look up the operator whose source representation is exactly `repr`.
+			///
+			/// Operators that can't be written in source code (such as [`Neg`](#variant.Neg)) are
+			/// never returned.
+			pub fn from_repr(repr: &str) -> Option<Self> {
+				$(
+					let candidate: Option<&'static str> = operator_enum!(; TRY_PARSE $repr $(($($ident)?))?);
+					if candidate == Some(repr) {
+						return Some(Operator::$variant);
+					}
+				)+
+
+				None
+			}
+
 			pub fn repr(&self) -> &'static str {
 				match self {
 					$(Operator::$variant => $repr),+
@@ -77,18 +112,18 @@ macro_rules! operator_enum {
 	};
 }
 
-// "Longer" operators need to be at the top so shorter ones don't overshadow them
+// Tokenizing always picks the longest match, so the order here doesn't affect parsing.
 operator_enum!{
 	// 3 characters
-	PowAssign("**=" 16) LshAssign("<<=" 16) RshAssign(">>=" 16) Cmp("<=>" 13)
+	PowAssign("**=" 16 RightToLeft) LshAssign("<<=" 16 RightToLeft) RshAssign(">>=" 16 RightToLeft) Cmp("<=>" 13)
 
 	// 2 characters
-	AddAssign("+=" 16) SubAssign("-=" 16) MulAssign("*=" 16) DivAssign("/=" 16) ModAssign("%=" 16) 
-	BAndAssign("&=" 16) BOrAssign("|=" 16) BXorAssign("^=" 16) Or("||" 15) And("&&" 14) Eql("==" 12)
-	Neq("!=" 12) Leq("<=" 11) Geq(">=" 11) Lsh("<<" 7) Rsh(">>" 7) Pow("**" 3) Scoped("::" 0)
+	AddAssign("+=" 16 RightToLeft) SubAssign("-=" 16 RightToLeft) MulAssign("*=" 16 RightToLeft) DivAssign("/=" 16 RightToLeft) ModAssign("%=" 16 RightToLeft) 
+	BAndAssign("&=" 16 RightToLeft) BOrAssign("|=" 16 RightToLeft) BXorAssign("^=" 16 RightToLeft) Or("||" 15) And("&&" 14) Eql("==" 12)
+	Neq("!=" 12) Leq("<=" 11) Geq(">=" 11) Lsh("<<" 7) Rsh(">>" 7) Pow("**" 3 RightToLeft) Scoped("::" 0)
 
 	// 1 Character
-	Assign("=" 16) Lth("<" 11) Gth(">" 11) BXor("^" 10) BOr("|" 9) BAnd("&" 8) Add("+" 6) Sub("-" 6)
+	Assign("=" 16 RightToLeft) Lth("<" 11) Gth(">" 11) BXor("^" 10) BOr("|" 9) BAnd("&" 8) Add("+" 6) Sub("-" 6)
 	Mul("*" 5) Div("/" 5) Mod("%" 5) Not("!" 2 UnaryOperOnLeft 1) BNot("~" 2 UnaryOperOnLeft 1)
 	Dot("." 0)
 
@@ -96,7 +131,7 @@ operator_enum!{
 	Neg("-@" () 4 UnaryOperOnLeft 1)
 	Pos("+@" () 2 UnaryOperOnLeft 1) 
 	RootScope("::@" () 0 UnaryOperOnLeft 1) 
-	DotAssign(".=" () 16)
+	DotAssign(".=" () 16 RightToLeft)
 	Call("()" () 0)
 	Index("[]" () 1)
 	WithBlock("{}" () 1)
@@ -157,3 +192,49 @@ impl quest_core::ToObject for Operator {
 		Object::from(self.to_string())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::stream::BufStream;
+
+	fn tokenize(src: &str) -> Vec<String> {
+		BufStream::from(src).tokens()
+			.map(|tkn| tkn.unwrap().to_string())
+			.collect()
+	}
+
+	#[test]
+	fn maximal_munch() {
+		assert_eq!(tokenize("a<b<=c<=>d"), ["a", "<", "b", "<=", "c", "<=>", "d"]);
+		assert_eq!(tokenize("a<=>b<=c<d"), ["a", "<=>", "b", "<=", "c", "<", "d"]);
+		assert_eq!(tokenize("<<=<=><<"), ["<<=", "<=>", "<<"]);
+		assert_eq!(tokenize("a**=b**c*d"), ["a", "**=", "b", "**", "c", "*", "d"]);
+		assert_eq!(tokenize("a==b=c"), ["a", "==", "b", "=", "c"]);
+	}
+
+	#[test]
+	fn from_repr() {
+		assert_eq!(Operator::from_repr("<=>"), Some(Operator::Cmp));
+		assert_eq!(Operator::from_repr("<="), Some(Operator::Leq));
+		assert_eq!(Operator::from_repr("<"), Some(Operator::Lth));
+		assert_eq!(Operator::from_repr("-@"), None);
+		assert_eq!(Operator::from_repr("<=>="), None);
+	}
+
+	#[test]
+	fn precedence_table() {
+		for &(oper, precedence) in Operator::PRECEDENCE_TABLE {
+			assert_eq!(oper.precedence(), precedence, "bad precedence for {}", oper);
+			assert!(precedence <= Operator::MAX_PRECEDENCE);
+		}
+
+		let lookup = |repr| Operator::PRECEDENCE_TABLE.iter()
+			.find(|(oper, _)| oper.repr() == repr)
+			.map(|&(_, precedence)| precedence);
+
+		assert_eq!(lookup("**"), Some(3));
+		assert!(lookup("<") < lookup("<=>"));
+		assert_eq!(lookup("<"), lookup("<="));
+	}
+}
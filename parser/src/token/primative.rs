@@ -49,7 +49,8 @@ pub enum Primative {
 impl Display for Primative {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		match self {
-			Primative::Text(t) => Display::fmt(&t, f),
+			// quoted and escaped so the literal can be re-tokenized.
+			Primative::Text(t) => write!(f, "{:?}", t.as_ref()),
 			Primative::Number(n) => Display::fmt(&n, f),
 			Primative::Variable(v) => Display::fmt(&v, f),
 			Primative::StackPos(s) => Display::fmt(&s, f),
@@ -380,6 +380,31 @@ mod tests {
 			assert_eq!(tkn(buf!("4.1e-4")), TokenizeResult::Some(num!(4.1e-4)));
 		}
 
+		#[test]
+		fn radix_prefixes() {
+			assert_eq!(tkn(buf!("0xff")), TokenizeResult::Some(num!(255)));
+			assert_eq!(tkn(buf!("0XFF")), TokenizeResult::Some(num!(255)));
+			assert_eq!(tkn(buf!("0xff_ff")), TokenizeResult::Some(num!(0xffff)));
+			assert_eq!(tkn(buf!("0o17")), TokenizeResult::Some(num!(15)));
+			assert_eq!(tkn(buf!("0O1_7")), TokenizeResult::Some(num!(15)));
+			assert_eq!(tkn(buf!("0b1010")), TokenizeResult::Some(num!(10)));
+			assert_eq!(tkn(buf!("0B10_10")), TokenizeResult::Some(num!(10)));
+			assert_eq!(tkn(buf!("0d1_9")), TokenizeResult::Some(num!(19)));
+
+			let buf = buf!("0x1f+1");
+			assert_eq!(tkn(buf), TokenizeResult::Some(num!(31)));
+			assert_eq!(buf.next().unwrap().unwrap(), '+');
+		}
+
+		#[test]
+		fn radix_prefix_without_digits() {
+			assert!(Number::try_tokenize(buf!("0x")).is_err());
+			assert!(Number::try_tokenize(buf!("0o ")).is_err());
+			assert!(Number::try_tokenize(buf!("0b_")).is_err());
+			assert!(Number::try_tokenize(buf!("0x+1")).is_err());
+			assert!(Number::try_tokenize(buf!("0b12")).is_err());
+		}
+
 		#[test]
 		fn after() {
 			let buf = buf!("4.1.2");
@@ -32,13 +32,17 @@ impl Tokenizable for StackPos {
 		match stream.next().transpose()? {
 			Some(':') => 
 				match stream.next().transpose()? {
-					// oops! this is the `::` operator
-					Some(':') => {
+					Some(chr) if chr.is_ascii_digit() || matches!(chr, '-' | '+' | '_') =>
+						try_seek!(stream, -1),
+					// oops! this is the `::` operator, or a lone `:` (as in `name: value`)
+					Some(_) => {
 						try_seek!(stream, -2);
 						return Ok(TokenizeResult::None);
 					},
-					Some(_) => try_seek!(stream, -1),
-					None => {}
+					None => {
+						try_seek!(stream, -1);
+						return Ok(TokenizeResult::None);
+					}
 				},
 			Some(_) => {
 				try_seek!(stream, -1);
@@ -1,6 +1,6 @@
 //! Parsing a literal text
 
-use crate::{Result, Stream};
+use crate::{Result, Stream, Context};
 use crate::expression::Executable;
 use crate::token::{Operator, Tokenizable, TokenizeResult};
 use crate::token::primative::Variable;
@@ -16,6 +16,23 @@ impl Executable for Text {
 	}
 }
 
+/// Parse an escape sequence, with the leading `\` already consumed.
+///
+/// Returns `None` for escaped newlines, which are ignored entirely.
+fn parse_escape<S: Stream>(stream: &mut S, starting_context: &Context) -> Result<Option<char>> {
+	use quest_core::escape::{self, Error, Syntax};
+
+	match escape::decode(Syntax::Quest, || stream.next().transpose()) {
+		Ok(chr) => Ok(chr),
+		Err(Error::Read(err)) => Err(err),
+		Err(Error::Unterminated) =>
+			Err(parse_error!(context=starting_context.clone(), UnterminatedQuote)),
+		Err(Error::BadEscape(chr)) => Err(parse_error!(stream, BadEscapeChar(chr))),
+		Err(Error::BadUnicode(hex)) => Err(parse_error!(stream, BadUnicodeEscape(hex))),
+		Err(Error::UnpairedSurrogate) => unreachable!("quest escapes don't have surrogates")
+	}
+}
+
 fn try_tokenize_quoted<S: Stream>(stream: &mut S, quote: char) -> Result<TokenizeResult<Text>> {
 	let mut text = String::new();
 
@@ -23,20 +40,7 @@ fn try_tokenize_quoted<S: Stream>(stream: &mut S, quote: char) -> Result<Tokeniz
 
 	while let Some(chr) = stream.next().transpose()? {
 		match chr {
-			'\\' => match stream.next().transpose()? {
-				Some(chr @ '\\')
-					| Some(chr @ '\'')
-					| Some(chr @ '\"') => text.push(chr),
-				Some('n') => text.push('\n'),
-				Some('\n') => { /* do nothing */ },
-				Some('t') => text.push('\t'),
-				Some('r') => text.push('\r'),
-				Some('0') => text.push('\0'),
-				Some('u') | Some('U')
-					| Some('x') | Some('X') => todo!("additional string parsing"),
-				Some(chr) => return Err(parse_error!(stream, BadEscapeChar(chr))),
-				None      => return Err(parse_error!(context=starting_context, UnterminatedQuote)),
-			},
+			'\\' => text.extend(parse_escape(stream, &starting_context)?),
 			chr if chr == quote => return Ok(TokenizeResult::Some(text.into())),
 			chr => text.push(chr)
 		}
@@ -45,6 +49,19 @@ fn try_tokenize_quoted<S: Stream>(stream: &mut S, quote: char) -> Result<Tokeniz
 	Err(parse_error!(context=starting_context, UnterminatedQuote))
 }
 
+// char literals are just a one-character text, as there's no dedicated char type.
+fn try_tokenize_char<S: Stream>(stream: &mut S) -> Result<TokenizeResult<Text>> {
+	let starting_context = stream.context().clone();
+
+	match try_tokenize_quoted(stream, '\'')? {
+		TokenizeResult::Some(text) if text.as_ref().chars().count() == 1 =>
+			Ok(TokenizeResult::Some(text)),
+		TokenizeResult::Some(text) =>
+			Err(parse_error!(context=starting_context, BadCharLiteral(text.as_ref().to_string()))),
+		other => Ok(other)
+	}
+}
+
 // valid syntax is `$variable_name` or `$operator`.
 fn try_tokenize_dollar_sign<S: Stream>(stream: &mut S) -> Result<TokenizeResult<Text>> {
 	macro_rules! from_other {
@@ -73,7 +90,8 @@ impl Tokenizable for Text {
 	fn try_tokenize<S: Stream>(stream: &mut S) -> Result<TokenizeResult<Self>> {
 		match stream.next().transpose()? {
 			Some('$') => try_tokenize_dollar_sign(stream),
-			Some(quote @ '\"') | Some(quote @ '\'') => try_tokenize_quoted(stream, quote),
+			Some('\"') => try_tokenize_quoted(stream, '\"'),
+			Some('\'') => try_tokenize_char(stream),
 			Some(_) => {
 				try_seek!(stream, -1);
 				Ok(TokenizeResult::None)
@@ -82,3 +100,101 @@ impl Tokenizable for Text {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ErrorType;
+	use crate::stream::BufStream;
+
+	fn tokenize(src: &'static str) -> Result<TokenizeResult<Text>> {
+		Text::try_tokenize(&mut BufStream::from(src))
+	}
+
+	macro_rules! assert_text {
+		($src:literal, $expected:expr) => {
+			assert_eq!(tokenize($src).unwrap(), TokenizeResult::Some(Text::from($expected)));
+		};
+	}
+
+	#[test]
+	fn quoted() {
+		assert_text!(r#""hello""#, "hello");
+		assert_text!(r#""it's""#, "it's");
+		assert_text!(r#""""#, "");
+	}
+
+	#[test]
+	fn chars() {
+		assert_text!("'a'", "a");
+		assert_text!("'\"'", "\"");
+		assert_text!("'\u{e9}'", "\u{e9}");
+		assert_text!(r#"'\n'"#, "\n");
+		assert_text!(r#"'\''"#, "'");
+		assert_text!(r#"'\u{1F600}'"#, "\u{1F600}");
+	}
+
+	#[test]
+	fn bad_chars() {
+		macro_rules! assert_bad_char {
+			($src:literal, $lit:literal) => {
+				match tokenize($src).unwrap_err().error_type() {
+					ErrorType::BadCharLiteral(lit) => assert_eq!(lit, $lit),
+					other => panic!("bad error for {:?}: {:?}", $src, other)
+				}
+			};
+		}
+
+		assert_bad_char!("'ab'", "ab");
+		assert_bad_char!("''", "");
+		assert_bad_char!(r#"'\n\t'"#, "\n\t");
+		assert!(matches!(tokenize("'a").unwrap_err().error_type(), ErrorType::UnterminatedQuote));
+	}
+
+	#[test]
+	fn escapes() {
+		assert_text!(r#""a\nb""#, "a\nb");
+		assert_text!(r#""a\tb""#, "a\tb");
+		assert_text!(r#""a\rb""#, "a\rb");
+		assert_text!(r#""a\0b""#, "a\0b");
+		assert_text!(r#""a\\b""#, "a\\b");
+		assert_text!(r#""a\"b""#, "a\"b");
+		assert_text!("\"a\\\nb\"", "ab");
+	}
+
+	#[test]
+	fn unicode_escapes() {
+		assert_text!(r#""\u{41}""#, "A");
+		assert_text!(r#""\u{e9}t\u{E9}""#, "été");
+		assert_text!(r#""\u{1F600}""#, "\u{1F600}");
+		assert_text!(r#""\u{0}""#, "\0");
+	}
+
+	#[test]
+	fn bad_escapes() {
+		macro_rules! assert_bad_escape {
+			($src:literal, $pat:pat) => {
+				let err = tokenize($src).unwrap_err();
+				assert!(matches!(err.error_type(), $pat), "bad error for {:?}: {:?}", $src, err);
+			};
+		}
+
+		assert_bad_escape!(r#""\q""#, ErrorType::BadEscapeChar('q'));
+		assert_bad_escape!(r#""\U0041""#, ErrorType::BadEscapeChar('U'));
+		assert_bad_escape!(r#""\x41""#, ErrorType::BadEscapeChar('x'));
+		assert_bad_escape!(r#""\X41""#, ErrorType::BadEscapeChar('X'));
+		assert_bad_escape!(r#""\u41""#, ErrorType::BadUnicodeEscape(_));
+		assert_bad_escape!(r#""\u{}""#, ErrorType::BadUnicodeEscape(_));
+		assert_bad_escape!(r#""\u{4g}""#, ErrorType::BadUnicodeEscape(_));
+		assert_bad_escape!(r#""\u{1234567}""#, ErrorType::BadUnicodeEscape(_));
+		assert_bad_escape!(r#""\u{D800}""#, ErrorType::BadUnicodeEscape(_));
+		assert_bad_escape!(r#""\u{41""#, ErrorType::BadUnicodeEscape(_));
+		assert_bad_escape!(r#""abc"#, ErrorType::UnterminatedQuote);
+	}
+
+	#[test]
+	fn bad_escape_position() {
+		let err = tokenize(r#""ab\q""#).unwrap_err();
+		assert_eq!(err.context().column, 5);
+	}
+}
@@ -2,6 +2,7 @@ use crate::{Result, Stream};
 use crate::token::{Tokenizable, TokenizeResult};
 use crate::expression::Executable;
 use std::fmt::{self, Display, Formatter};
+use std::cell::RefCell;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
 pub struct Variable(quest_core::types::Text);
@@ -30,31 +31,32 @@ fn is_variable_body(c: char) -> bool {
 	is_variable_start(c) || c.is_ascii_digit()
 }
 
+thread_local! {
+	// reused for every identifier, so tokenizing one that's already interned doesn't allocate.
+	static SCRATCH: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
 impl Tokenizable for Variable {
 	type Item = Self;
 	fn try_tokenize<S: Stream>(stream: &mut S) -> Result<TokenizeResult<Self>> {
-		let mut variable =
-			match stream.next().transpose()? {
-				Some(chr) if is_variable_start(chr) => chr.to_string(),
-				Some(_) => {
-					try_seek!(stream, -1);
-					return Ok(TokenizeResult::None)
-				},
-				None => return Ok(TokenizeResult::None)
-			};
-
-		while let Some(chr) = stream.next().transpose()? { 
-			if is_variable_body(chr) {
-				variable.push(chr)
-			} else {
-				try_seek!(stream, -1);
-				break;
-			}
+		match stream.peek_char()? {
+			Some(chr) if is_variable_start(chr) => SCRATCH.with(|variable| {
+				let mut variable = variable.borrow_mut();
+				variable.clear();
+
+				while let Some(chr) = stream.peek_char()? {
+					if !is_variable_body(chr) {
+						break;
+					}
+
+					variable.push(chr);
+					stream.next().transpose()?;
+				}
+
+				// identifiers recur constantly, so share their storage instead of allocating each one.
+				Ok(TokenizeResult::Some(Variable(quest_core::types::Text::interned(&variable))))
+			}),
+			_ => Ok(TokenizeResult::None)
 		}
-
-		variable.shrink_to_fit();
-
-		Ok(TokenizeResult::Some(Variable(variable.into())))
 	}
 }
-
@@ -15,7 +15,8 @@ pub enum Token {
 	Left(ParenType),
 	Right(ParenType),
 	Endline,
-	Comma
+	Comma,
+	Colon
 }
 
 impl Display for Token {
@@ -27,6 +28,7 @@ impl Display for Token {
 			Token::Right(t) => Display::fmt(&t.right(), f),
 			Token::Endline => write!(f, ";"),
 			Token::Comma => write!(f, ","),
+			Token::Colon => write!(f, ":"),
 		}		
 	}
 }
@@ -53,12 +55,50 @@ impl Token {
 		match stream.next().transpose()? {
 			Some(';') => Ok(Some(Token::Endline)),
 			Some(',') => Ok(Some(Token::Comma)),
+			Some(':') => Ok(Some(Token::Colon)),
 			Some(chr) => Err(parse_error!(stream, UnknownTokenStart(chr))),
 			None => Ok(None)
 		}
 	}
 }
 
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::stream::BufStream;
 
+	fn tokenize(src: &str) -> Vec<Token> {
+		BufStream::from(src).tokens().collect::<Result<_>>().unwrap()
+	}
+
+	#[test]
+	fn display() {
+		assert_eq!(Token::Left(ParenType::Curly).to_string(), "{");
+		assert_eq!(Token::Right(ParenType::Square).to_string(), "]");
+		assert_eq!(Token::Endline.to_string(), ";");
+		assert_eq!(Token::Comma.to_string(), ",");
+		assert_eq!(Token::Colon.to_string(), ":");
+		assert_eq!(Token::Operator(Operator::Pow).to_string(), "**");
+		assert_eq!(
+			Token::Primative(Primative::Text("a\"b\n".into())).to_string(),
+			r#""a\"b\n""#
+		);
+	}
+
+	#[test]
+	fn round_trip() {
+		const PROGRAM: &str = r#"
+			$fib = {
+				if(_1 <= 1, { _1 }, { fib(_1 - 1) + fib(_1 - 2) })();
+			};
+			disp("fib:", fib(10), 'x', "\t\"q\"\u{e9}", :1, $+, [1, 2.5], sep: " ");
+			Kernel::$x .= 3 ** -4;
+		"#;
+
+		let tokens = tokenize(PROGRAM);
+		let rendered = tokens.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ");
 
+		assert_eq!(tokenize(&rendered), tokens);
+	}
+}
 
@@ -15,18 +15,61 @@ impl From<Never> for super::Token {
 impl Tokenizable for Whitespace {
 	type Item = Never;
 	fn try_tokenize<S: Stream>(stream: &mut S) -> Result<TokenizeResult<Never>> {
-		match stream.next().transpose()? {
+		match stream.peek_char()? {
+			// a trailing `\` continues the current line onto the next one.
+			Some('\\') => {
+				stream.next().transpose()?;
+
+				match stream.next().transpose()? {
+					Some('\n') => return Ok(TokenizeResult::RestartParsing),
+					Some('\r') if stream.next().transpose()? == Some('\n') =>
+						return Ok(TokenizeResult::RestartParsing),
+					_ => return Err(parse_error!(stream, StrayBackslash))
+				}
+			},
 			Some(chr) if chr.is_whitespace() =>
-				while let Some(chr) = stream.next().transpose()? {
+				while let Some(chr) = stream.peek_char()? {
 					if !chr.is_whitespace() {
-						try_seek!(stream, -1);
 						return Ok(TokenizeResult::RestartParsing);
 					}
+
+					stream.next().transpose()?;
 				},
-			Some(_) => try_seek!(stream, -1),
-			None => {}
+			_ => {}
 		}
 
 		Ok(TokenizeResult::None)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::token::{Token, Operator, Primative};
+	use crate::stream::{BufStream, Stream};
+	use crate::error::ErrorType;
+	use crate::Result;
+
+	fn tokenize(src: &str) -> Result<Vec<Token>> {
+		BufStream::from(src).tokens().collect()
+	}
+
+	#[test]
+	fn line_continuation() {
+		let tokens = tokenize("1 + \\\n\t2 * \\\r\n3").unwrap();
+
+		assert_eq!(tokens, tokenize("1 + 2 * 3").unwrap());
+		assert_eq!(tokens.len(), 5);
+		assert_eq!(tokens[1], Token::Operator(Operator::Add));
+		assert!(matches!(tokens[4], Token::Primative(Primative::Number(_))));
+	}
+
+	#[test]
+	fn stray_backslash() {
+		for src in &["1 \\ 2", "1 +\\", "\\x"] {
+			match tokenize(src) {
+				Err(err) => assert!(matches!(err.error_type(), ErrorType::StrayBackslash), "{:?}", err),
+				Ok(tokens) => panic!("{:?} tokenized to {:?}", src, tokens)
+			}
+		}
+	}
+}